@@ -1,54 +1,21 @@
-use std::fs;
-use crate::Part::{Part1, Part2};
+use common::{ParseError, Solution};
 
-#[derive(PartialEq, Debug)]
-enum Part {
-    Part1,
-    Part2,
-}
-
-fn get_value(file_path: &str, part: Part) -> i32 {
-    let file_contents =
-        fs::read_to_string(file_path).expect("Should have been able to read the file");
-
-    for line in file_contents.lines() {
-
-    }
-
-   if part == Part1 { 8 } else { 4 }
-}
-
-fn main() {
-    println!("Part 1 value: {}", get_value("./input.txt", Part1));
-    println!("Part 2 value: {}", get_value("./input.txt", Part2));
-}
+pub struct DayXX;
 
-#[cfg(test)]
-mod tests {
-    use crate::get_value;
-    use crate::Part::{Part1, Part2};
+impl Solution for DayXX {
+    type Input = Vec<String>;
+    type Answer1 = Result<i32, &'static str>;
+    type Answer2 = Result<i32, &'static str>;
 
-    #[test]
-    fn returns_expected_value_test_data_for_part_1() {
-        let value = get_value("./test.txt", Part1);
-        assert_eq!(value, 8);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
     }
 
-    #[test]
-    fn returns_expected_value_for_input_data_for_part_1() {
-        let value = get_value("./input.txt", Part1);
-        assert_eq!(value, 8);
+    fn part1(_lines: &Self::Input) -> Result<i32, &'static str> {
+        Err("not solved yet")
     }
 
-    #[test]
-    fn returns_expected_value_test_data_for_part_2() {
-        let value = get_value("./test.txt", Part2);
-        assert_eq!(value, 4);
+    fn part2(_lines: &Self::Input) -> Result<i32, &'static str> {
+        Err("not solved yet")
     }
-
-    #[test]
-    fn returns_expected_value_for_input_data_for_part_2() {
-        let value = get_value("./input.txt", Part2);
-        assert_eq!(value, 4);
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,312 @@
+use crate::days::Day;
+use common::Part;
+use serde::Deserialize;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One known answer: what `day` should print for `part` when given the puzzle input at `input`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub expected: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let answers: Self = toml::from_str(contents).map_err(|error| error.to_string())?;
+
+        for answer in &answers.answers {
+            if !(1..=25).contains(&answer.day) {
+                return Err(format!("day must be between 1 and 25, got {}", answer.day));
+            }
+            Part::try_from(answer.part)?;
+        }
+
+        Ok(answers)
+    }
+
+    /// Loads the registry at `path`, resolving every `input` relative to the file itself.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("failed to read `{}`: {error}", path.display()))?;
+        let mut answers =
+            Self::parse(&contents).map_err(|error| format!("`{}`: {error}", path.display()))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        for answer in &mut answers.answers {
+            answer.input = base.join(&answer.input);
+        }
+
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { actual: String },
+    Error(String),
+    Missing,
+}
+
+impl Outcome {
+    /// Missing answers are reported but do not count as failures.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail { .. } => "FAIL",
+            Outcome::Error(_) => "ERROR",
+            Outcome::Missing => "missing",
+        }
+    }
+}
+
+/// The result of checking one day and part, either against a registered answer or, when none
+/// is registered, recording that it is missing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub outcome: Outcome,
+}
+
+fn check(solution: &Day, part: Part, answer: &Answer) -> Outcome {
+    let contents = match fs::read_to_string(&answer.input) {
+        Ok(contents) => contents,
+        Err(error) => {
+            return Outcome::Error(format!(
+                "failed to read `{}`: {error}",
+                answer.input.display()
+            ))
+        }
+    };
+
+    match (solution.solve)(&contents, part) {
+        Ok(actual) if actual == answer.expected => Outcome::Pass,
+        Ok(actual) => Outcome::Fail { actual },
+        Err(error) => Outcome::Error(error.to_string()),
+    }
+}
+
+/// Runs every part of `days` against each of its registered answers.
+pub fn verify(days: &[&Day], answers: &Answers) -> Vec<Check> {
+    let mut checks = vec![];
+
+    for solution in days {
+        for part in Part::ALL {
+            let registered: Vec<&Answer> = answers
+                .answers
+                .iter()
+                .filter(|answer| answer.day == solution.number && answer.part == part.number())
+                .collect();
+
+            if registered.is_empty() {
+                checks.push(Check {
+                    day: solution.number,
+                    part,
+                    answer: None,
+                    outcome: Outcome::Missing,
+                });
+            }
+
+            for answer in registered {
+                checks.push(Check {
+                    day: solution.number,
+                    part,
+                    answer: Some(answer.clone()),
+                    outcome: check(solution, part, answer),
+                });
+            }
+        }
+    }
+
+    checks
+}
+
+/// Renders one row per check, followed by a count of each outcome.
+pub fn render_table(checks: &[Check]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:<24}  {:<7}  {}\n",
+        "Day", "Part", "Input", "Status", "Details"
+    );
+
+    for check in checks {
+        let input = check.answer.as_ref().map_or(String::from("-"), |answer| {
+            answer.input.display().to_string()
+        });
+        let details = match (&check.outcome, &check.answer) {
+            (Outcome::Fail { actual }, Some(answer)) => {
+                format!("expected {}, got {actual}", answer.expected)
+            }
+            (Outcome::Error(error), _) => error.clone(),
+            _ => String::new(),
+        };
+
+        let row = format!(
+            "{:>3}  {:>4}  {:<24}  {:<7}  {}",
+            check.day,
+            check.part.number(),
+            input,
+            check.outcome.label(),
+            details
+        );
+        let _ = writeln!(table, "{}", row.trim_end());
+    }
+
+    let count = |label: &str| {
+        checks
+            .iter()
+            .filter(|check| check.outcome.label() == label)
+            .count()
+    };
+    let _ = writeln!(
+        table,
+        "\n{} passed, {} failed, {} errors, {} missing",
+        count("pass"),
+        count("FAIL"),
+        count("ERROR"),
+        count("missing")
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_table, verify, Answers, Outcome};
+    use crate::days::{Day, DAYS};
+    use common::error::{parse_lines, parse_number};
+    use common::{ParseError, Part, Solution};
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::thread;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse_lines(input, parse_number)
+        }
+
+        fn part1(numbers: &Self::Input) -> i32 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Self::Input) -> usize {
+            numbers.len()
+        }
+    }
+
+    const SUM: Day = Day {
+        number: 1,
+        solve: Sum::solve,
+        time: crate::bench::time::<Sum>,
+    };
+
+    #[test]
+    fn parses_the_answer_registry() {
+        let answers = Answers::parse(
+            "[[answer]]\nday = 7\npart = 2\ninput = \"day-07/input.txt\"\nexpected = \"11387\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.answers.len(), 1);
+        assert_eq!(answers.answers[0].expected, "11387");
+        assert!(
+            Answers::parse("[[answer]]\nday = 7\npart = 3\ninput = \"x\"\nexpected = \"1\"\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn reports_passing_failing_and_missing_answers() {
+        let dir = env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "1\n2\n3\n").unwrap();
+        fs::write(
+            dir.join("answers.toml"),
+            "[[answer]]\nday = 1\npart = 1\ninput = \"input.txt\"\nexpected = \"6\"\n\n\
+             [[answer]]\nday = 1\npart = 1\ninput = \"input.txt\"\nexpected = \"7\"\n",
+        )
+        .unwrap();
+
+        let answers = Answers::load(&dir.join("answers.toml")).unwrap();
+        let checks = verify(&[&SUM], &answers);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let outcomes: Vec<_> = checks.iter().map(|check| &check.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                &Outcome::Pass,
+                &Outcome::Fail {
+                    actual: "6".to_string()
+                },
+                &Outcome::Missing
+            ]
+        );
+        assert_eq!(checks[2].part, Part::Part2);
+        assert!(render_table(&checks).ends_with("1 passed, 1 failed, 0 errors, 1 missing\n"));
+    }
+
+    #[test]
+    fn reports_inputs_that_fail_to_parse() {
+        let dir = env::temp_dir().join(format!("aoc-verify-error-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "1\nx\n").unwrap();
+        let mut answers = Answers::parse(
+            "[[answer]]\nday = 1\npart = 2\ninput = \"input.txt\"\nexpected = \"2\"\n",
+        )
+        .unwrap();
+        answers.answers[0].input = dir.join("input.txt");
+
+        let checks = verify(&[&SUM], &answers);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Error("line 2: expected a number, found `x`".to_string())
+        );
+    }
+
+    #[test]
+    fn every_registered_answer_passes() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+        let answers = Answers::load(&path).unwrap();
+
+        let checks = thread::scope(|scope| {
+            let days: Vec<_> = DAYS
+                .iter()
+                .map(|day| scope.spawn(|| verify(&[day], &answers)))
+                .collect();
+            days.into_iter()
+                .flat_map(|day| day.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let failures: Vec<_> = checks
+            .iter()
+            .filter(|check| check.outcome.is_failure())
+            .collect();
+        assert!(failures.is_empty(), "{}", render_table(&checks));
+    }
+}
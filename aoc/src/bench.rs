@@ -0,0 +1,159 @@
+use common::{Answer, Part, Solution, SolveError};
+use serde::Serialize;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long one run of a day spent in each stage.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+fn failed(part: Part) -> impl FnOnce(String) -> SolveError {
+    move |message| SolveError::Part { part, message }
+}
+
+/// Parses `input` and solves both parts once, timing each stage on its own. A part that fails is
+/// reported rather than timed.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, SolveError> {
+    let start = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = black_box(S::part1(&parsed));
+    let part1 = start.elapsed();
+    answer.into_answer().map_err(failed(Part::Part1))?;
+
+    let start = Instant::now();
+    let answer = black_box(S::part2(&parsed));
+    let part2 = start.elapsed();
+    answer.into_answer().map_err(failed(Part::Part2))?;
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub median_ns: u128,
+    pub min_ns: u128,
+}
+
+impl Stats {
+    fn from_samples(samples: impl IntoIterator<Item = Duration>) -> Self {
+        let mut samples: Vec<u128> = samples.into_iter().map(|d| d.as_nanos()).collect();
+        samples.sort_unstable();
+
+        Self {
+            median_ns: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+            min_ns: samples.first().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayReport {
+    pub fn new(day: u8, runs: &[Timings]) -> Self {
+        Self {
+            day,
+            iterations: runs.len(),
+            parse: Stats::from_samples(runs.iter().map(|run| run.parse)),
+            part1: Stats::from_samples(runs.iter().map(|run| run.part1)),
+            part2: Stats::from_samples(runs.iter().map(|run| run.part2)),
+        }
+    }
+}
+
+fn format_stats(stats: Stats) -> String {
+    let median = Duration::from_nanos(stats.median_ns as u64);
+    let min = Duration::from_nanos(stats.min_ns as u64);
+    format!("{median:.2?} / {min:.2?}")
+}
+
+/// Renders one row per day with the median and minimum time of every stage.
+pub fn render_table(reports: &[DayReport]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>23}  {:>23}  {:>23}\n",
+        "Day", "parse (median / min)", "part 1 (median / min)", "part 2 (median / min)"
+    );
+
+    for report in reports {
+        let _ = writeln!(
+            table,
+            "{:>3}  {:>23}  {:>23}  {:>23}",
+            report.day,
+            format_stats(report.parse),
+            format_stats(report.part1),
+            format_stats(report.part2)
+        );
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_table, DayReport, Stats, Timings};
+    use std::time::Duration;
+
+    fn run(parse: u64, part1: u64, part2: u64) -> Timings {
+        Timings {
+            parse: Duration::from_micros(parse),
+            part1: Duration::from_micros(part1),
+            part2: Duration::from_micros(part2),
+        }
+    }
+
+    #[test]
+    fn summarizes_each_stage_by_median_and_minimum() {
+        let report = DayReport::new(6, &[run(3, 10, 900), run(1, 30, 700), run(2, 20, 800)]);
+
+        assert_eq!(report.iterations, 3);
+        assert_eq!(
+            report.parse,
+            Stats {
+                median_ns: 2_000,
+                min_ns: 1_000
+            }
+        );
+        assert_eq!(report.part2.median_ns, 800_000);
+        assert_eq!(report.part2.min_ns, 700_000);
+    }
+
+    #[test]
+    fn writes_a_row_per_day() {
+        let reports = [
+            DayReport::new(1, &[run(1, 1, 1)]),
+            DayReport::new(2, &[run(1, 1, 1)]),
+        ];
+        let table = render_table(&reports);
+
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.lines().nth(2).unwrap().trim_start().starts_with('2'));
+    }
+
+    #[test]
+    fn serializes_reports_as_json() {
+        let report = DayReport::new(8, &[run(1, 2, 3)]);
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":8,"iterations":1,"parse":{"median_ns":1000,"min_ns":1000},"part1":{"median_ns":2000,"min_ns":2000},"part2":{"median_ns":3000,"min_ns":3000}}"#
+        );
+    }
+}
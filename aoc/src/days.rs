@@ -0,0 +1,48 @@
+use crate::bench::{self, Timings};
+use common::{Part, Solution, SolveError};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part) -> Result<String, SolveError>,
+    pub time: fn(&str) -> Result<Timings, SolveError>,
+}
+
+const fn day<S: Solution>(number: u8) -> Day {
+    Day {
+        number,
+        solve: S::solve,
+        time: bench::time::<S>,
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day::<day_01::Day01>(1),
+    day::<day_02::Day02>(2),
+    day::<day_03::Day03>(3),
+    day::<day_04::Day04>(4),
+    day::<day_05::Day05>(5),
+    day::<day_06::Day06>(6),
+    day::<day_07::Day07>(7),
+    day::<day_08::Day08>(8),
+    day::<day_09::Day09>(9),
+    day::<day_10::Day10>(10),
+    day::<day_11::Day11>(11),
+    day::<day_12::Day12>(12),
+    day::<day_13::Day13>(13),
+    day::<day_14::Day14>(14),
+    day::<day_15::Day15>(15),
+    day::<day_16::Day16>(16),
+    day::<day_17::Day17>(17),
+    day::<day_18::Day18>(18),
+    day::<day_19::Day19>(19),
+    day::<day_20::Day20>(20),
+    day::<day_21::Day21>(21),
+    day::<day_22::Day22>(22),
+    day::<day_23::Day23>(23),
+    day::<day_24::Day24>(24),
+    day::<day_25::Day25>(25),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
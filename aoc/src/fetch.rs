@@ -0,0 +1,250 @@
+use common::Part;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = "github.com/barclayd/advent-of-code-2024 (aoc runner)";
+
+/// Reads the Advent of Code session cookie from `SESSION_COOKIE`, falling back to a
+/// `SESSION_COOKIE=...` line in the `.env` file at `dotenv`.
+pub fn session_cookie(dotenv: &Path) -> Option<String> {
+    if let Ok(session) = env::var("SESSION_COOKIE") {
+        return Some(session).filter(|session| !session.is_empty());
+    }
+
+    fs::read_to_string(dotenv)
+        .ok()?
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("SESSION_COOKIE="))
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .find(|session| !session.is_empty())
+}
+
+/// A minimal Advent of Code client. The base URL is configurable so it can be pointed at a
+/// local stand-in server.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            String::from("no session cookie: set SESSION_COOKIE or add it to `.env`")
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{YEAR}/day/{path}", self.base_url)
+    }
+
+    /// Sends the session cookie when there is one; puzzle pages are public but only show the
+    /// second part once the first has been solved.
+    fn get(&self, day: u8, path: &str) -> Result<String, String> {
+        let url = self.url(path);
+        let mut request = self.agent.get(&url);
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={session}"));
+        }
+        let response = request
+            .call()
+            .map_err(|error| describe_error(day, &url, error))?;
+
+        response
+            .into_string()
+            .map_err(|error| format!("failed to read the response from {url}: {error}"))
+    }
+
+    /// Downloads the puzzle input for `day`, rejecting anything that is not plain puzzle input.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        self.session()?;
+        let input = self.get(day, &format!("{day}/input"))?;
+        validate_input(day, &input)?;
+        Ok(input)
+    }
+
+    /// Downloads the puzzle page for `day`.
+    pub fn puzzle(&self, day: u8) -> Result<String, String> {
+        self.get(day, &day.to_string())
+    }
+
+    /// Posts `answer` for one part of `day` and returns the page the server responds with.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<String, String> {
+        let url = self.url(&format!("{day}/answer"));
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|error| describe_error(day, &url, error))?;
+
+        response
+            .into_string()
+            .map_err(|error| format!("failed to read the response from {url}: {error}"))
+    }
+}
+
+fn describe_error(day: u8, url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(400, _) => {
+            String::from("the session cookie was rejected; copy a fresh one from the browser")
+        }
+        ureq::Error::Status(404, _) => format!("day {day} has not unlocked yet"),
+        ureq::Error::Status(status, _) => format!("{url} returned HTTP {status}"),
+        ureq::Error::Transport(error) => format!("failed to reach {url}: {error}"),
+    }
+}
+
+fn validate_input(day: u8, input: &str) -> Result<(), String> {
+    let start = input.trim_start().to_ascii_lowercase();
+
+    if start.is_empty() {
+        Err(format!("the input for day {day} was empty"))
+    } else if start.starts_with('<') || start.contains("<html") {
+        Err(format!(
+            "the server returned an HTML page instead of the input for day {day}"
+        ))
+    } else if start.starts_with("puzzle inputs differ by user") {
+        Err(String::from(
+            "the server asked to log in; check the session cookie",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Keeps every downloaded input on disk so each one is only requested from the server once.
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.root
+            .join(YEAR.to_string())
+            .join(format!("day-{day:02}"))
+    }
+
+    /// Returns the cached input for `day`, downloading it with `client` on a miss.
+    pub fn input(&self, client: &Client, day: u8) -> Result<String, String> {
+        let path = self.day_dir(day).join("input.txt");
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        let input = client.input(day)?;
+        fs::create_dir_all(self.day_dir(day))
+            .and_then(|()| fs::write(&path, &input))
+            .map_err(|error| format!("failed to cache `{}`: {error}", path.display()))?;
+
+        Ok(input)
+    }
+}
+
+/// Fails if `path` already holds an input; an empty placeholder may be replaced.
+pub fn ensure_writable(path: &Path) -> Result<(), String> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() > 0 => Err(format!(
+            "`{}` already exists; delete it to fetch the input again",
+            path.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Fetches the input for `day` through `cache` into `path`, refusing to overwrite an existing one.
+pub fn fetch_input(client: &Client, cache: &Cache, day: u8, path: &Path) -> Result<(), String> {
+    ensure_writable(path)?;
+    let input = cache.input(client, day)?;
+    fs::write(path, input).map_err(|error| format!("failed to write `{}`: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fetch_input, Cache, Client};
+    use crate::test_server;
+    use std::env;
+    use std::fs;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn downloads_an_input_once_and_serves_it_from_the_cache() {
+        let server = test_server::serve(vec![(200, "3   4\n4   3\n")]);
+        let client = Client::new(&server.url, Some(String::from("abc")));
+        let dir = temp_dir("fetch");
+        let cache = Cache::new(dir.join("cache"));
+
+        assert_eq!(cache.input(&client, 1).unwrap(), "3   4\n4   3\n");
+        assert_eq!(cache.input(&client, 1).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_error_pages_instead_of_saving_them() {
+        let server = test_server::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (200, "<!DOCTYPE html>\n<html lang=\"en-us\">"),
+        ]);
+        let client = Client::new(&server.url, Some(String::from("abc")));
+
+        assert!(client
+            .input(1)
+            .unwrap_err()
+            .contains("session cookie was rejected"));
+        assert_eq!(client.input(2).unwrap_err(), "day 2 has not unlocked yet");
+        assert!(client.input(3).unwrap_err().contains("HTML page"));
+        assert!(Client::new(&server.url, None)
+            .input(4)
+            .unwrap_err()
+            .starts_with("no session cookie"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_an_existing_input() {
+        let dir = temp_dir("fetch-existing");
+        let path = dir.join("input.txt");
+        fs::write(&path, "1\n").unwrap();
+        let client = Client::new("http://127.0.0.1:9", Some(String::from("abc")));
+
+        let error = fetch_input(&client, &Cache::new(dir.join("cache")), 1, &path).unwrap_err();
+
+        assert!(error.ends_with("already exists; delete it to fetch the input again"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,344 @@
+mod answers;
+mod bench;
+mod days;
+mod fetch;
+mod puzzle;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod test_server;
+
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use common::{Part, Solution};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day
+    Run {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to run; both parts are run when omitted
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Puzzle input file, or `-` to read it from stdin; defaults to `day-NN/input.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of each day's solver against its `input.txt`
+    Bench {
+        /// Day to benchmark; every registered day is benchmarked when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Number of timed runs per day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Where to write the JSON report
+        #[arg(short, long, default_value = "target/bench.json")]
+        report: PathBuf,
+    },
+    /// Check every solver against the known answers in `answers.toml`
+    Verify {
+        /// Day to verify; every registered day is verified when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Answer registry to check against
+        #[arg(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Download a day's puzzle input into `day-NN/input.txt`
+    Fetch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Create `day-NN` from `aoc.template.rs`, its example and answers, and fetch its input
+    Scaffold {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Saved puzzle page to read the example from instead of downloading it
+        #[arg(long)]
+        puzzle: Option<PathBuf>,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Solve one part and submit the answer, unless it is already known to be wrong
+    Submit {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(short, long, value_parser = parse_part)]
+        part: Part,
+        /// Puzzle input file, or `-` to read it from stdin; defaults to `day-NN/input.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Draw day 24's gate network as a Graphviz or Mermaid graph
+    #[command(name = "day24", group(ArgGroup::new("format").required(true)))]
+    Day24 {
+        /// Print a Graphviz DOT digraph
+        #[arg(long, group = "format")]
+        dot: bool,
+        /// Print a Mermaid flowchart
+        #[arg(long, group = "format")]
+        mermaid: bool,
+        /// Puzzle input file, or `-` to read it from stdin; defaults to `day-24/input.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct Remote {
+    /// Server to talk to instead of adventofcode.com
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Directory where downloaded inputs are cached
+    #[arg(long, default_value = ".aoc-cache")]
+    cache: PathBuf,
+}
+
+impl Remote {
+    fn client(&self) -> fetch::Client {
+        fetch::Client::new(&self.base_url, fetch::session_cookie(Path::new(".env")))
+    }
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day-{:02}", day)).join("input.txt")
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|error| format!("failed to read stdin: {error}"))?;
+        return Ok(contents);
+    }
+
+    fs::read_to_string(path)
+        .map_err(|error| format!("failed to read `{}`: {error}", path.display()))
+}
+
+fn find_day(day: u8) -> Result<&'static days::Day, String> {
+    days::find(day).ok_or_else(|| format!("no solver registered for day {day}"))
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let number: u8 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a part number"))?;
+    Part::try_from(number)
+}
+
+fn run(day: u8, part: Option<Part>, input: Option<PathBuf>) -> Result<(), String> {
+    let solution = find_day(day)?;
+    let input = input.unwrap_or_else(|| default_input_path(day));
+    let contents = read_input(&input)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    for part in parts {
+        let answer =
+            (solution.solve)(&contents, part).map_err(|error| format!("day {day}, {error}"))?;
+        println!("Day {day} part {}: {answer}", part.number());
+    }
+
+    Ok(())
+}
+
+fn bench(day: Option<u8>, iterations: u64, report: &Path) -> Result<(), String> {
+    let selected: Vec<&days::Day> = match day {
+        Some(day) => vec![find_day(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut reports = vec![];
+    for solution in selected {
+        let day = solution.number;
+        let contents = read_input(&default_input_path(day))?;
+        let runs = (0..iterations)
+            .map(|_| (solution.time)(&contents))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| format!("day {day}, {error}"))?;
+        reports.push(bench::DayReport::new(day, &runs));
+    }
+
+    print!("{}", bench::render_table(&reports));
+
+    let json = serde_json::to_string_pretty(&reports)
+        .map_err(|error| format!("failed to serialize the report: {error}"))?;
+    if let Some(parent) = report
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("failed to create `{}`: {error}", parent.display()))?;
+    }
+    fs::write(report, json + "\n")
+        .map_err(|error| format!("failed to write `{}`: {error}", report.display()))?;
+    println!("Wrote {}", report.display());
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, answers: &Path) -> Result<(), String> {
+    let selected: Vec<&days::Day> = match day {
+        Some(day) => vec![find_day(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+    let answers = answers::Answers::load(answers)?;
+
+    let checks = answers::verify(&selected, &answers);
+    print!("{}", answers::render_table(&checks));
+
+    let failures = checks
+        .iter()
+        .filter(|check| check.outcome.is_failure())
+        .count();
+    match failures {
+        0 => Ok(()),
+        1 => Err(String::from("1 answer did not match")),
+        n => Err(format!("{n} answers did not match")),
+    }
+}
+
+fn fetch(day: u8, remote: &Remote) -> Result<(), String> {
+    let path = default_input_path(day);
+    let cache = fetch::Cache::new(&remote.cache);
+    fetch::fetch_input(&remote.client(), &cache, day, &path)?;
+    println!("Wrote {}", path.display());
+
+    Ok(())
+}
+
+fn load_puzzle(day: u8, path: Option<&Path>, remote: &Remote) -> Result<puzzle::Puzzle, String> {
+    let html = match path {
+        Some(path) => read_input(path)?,
+        None => remote.client().puzzle(day)?,
+    };
+    puzzle::Puzzle::parse(&html)
+}
+
+fn scaffold(day: u8, puzzle: Option<&Path>, remote: &Remote) -> Result<(), String> {
+    let puzzle = match load_puzzle(day, puzzle, remote) {
+        Ok(puzzle) => Some(puzzle),
+        Err(error) => {
+            eprintln!("warning: no example was filled in: {error}");
+            None
+        }
+    };
+    let dir = scaffold::scaffold(Path::new("."), day, puzzle.as_ref())?;
+    println!("Created {}", dir.display());
+
+    if let Some(puzzle) = &puzzle {
+        let readme = Path::new("README.md");
+        let contents = read_input(readme)?;
+        fs::write(readme, scaffold::update_progress(&contents, day, puzzle))
+            .map_err(|error| format!("failed to write `{}`: {error}", readme.display()))?;
+        println!("Added day {day} to the README progress table");
+    }
+
+    if let Err(error) = fetch(day, remote) {
+        eprintln!("warning: could not fetch the input yet: {error}");
+        eprintln!("run `aoc fetch --day {day}` once it is available");
+    }
+    println!("Register Day{day:02} in aoc/Cargo.toml and aoc/src/days.rs to run it through `aoc`");
+
+    Ok(())
+}
+
+fn submit(day: u8, part: Part, input: Option<PathBuf>, remote: &Remote) -> Result<(), String> {
+    let solution = find_day(day)?;
+    let input = input.unwrap_or_else(|| default_input_path(day));
+    let answer = (solution.solve)(&read_input(&input)?, part)
+        .map_err(|error| format!("day {day}, {error}"))?;
+
+    let path = fetch::Cache::new(&remote.cache)
+        .day_dir(day)
+        .join("submissions.json");
+    let mut history = submit::History::load(&path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    history.check(part, &answer, now)?;
+
+    println!("Submitting {answer} for day {day} part {}", part.number());
+    let verdict = submit::Verdict::parse(&remote.client().submit(day, part, &answer)?)?;
+    history.record(part, &answer, verdict, now);
+    history.save(&path)?;
+
+    match verdict {
+        submit::Verdict::Correct => {
+            println!("{verdict}");
+            Ok(())
+        }
+        _ => Err(verdict.to_string()),
+    }
+}
+
+fn day24(mermaid: bool, input: Option<PathBuf>) -> Result<(), String> {
+    let input = input.unwrap_or_else(|| default_input_path(24));
+    let device = day_24::Day24::parse(&read_input(&input)?)
+        .map_err(|error| format!("day 24, {error}"))?;
+
+    if mermaid {
+        print!("{}", day_24::graph::to_mermaid(&device));
+    } else {
+        print!("{}", day_24::graph::to_dot(&device));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Bench {
+            day,
+            iterations,
+            report,
+        } => bench(day, iterations, &report),
+        Command::Verify { day, answers } => verify(day, &answers),
+        Command::Fetch { day, remote } => fetch(day, &remote),
+        Command::Scaffold {
+            day,
+            puzzle,
+            remote,
+        } => scaffold(day, puzzle.as_deref(), &remote),
+        Command::Submit {
+            day,
+            part,
+            input,
+            remote,
+        } => submit(day, part, input, &remote),
+        Command::Day24 { mermaid, input, .. } => day24(mermaid, input),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use scraper::{ElementRef, Html, Selector};
+
+/// What the scaffold needs from a puzzle page: its title, the first example input, the answer
+/// emphasised in each part's description and how many parts have been solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: String,
+    pub example: Option<String>,
+    pub answers: [Option<String>; 2],
+    pub stars: usize,
+}
+
+fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).expect("selectors are valid")
+}
+
+fn is_inside_pre(element: &ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(|node| node.value().as_element())
+        .any(|ancestor| ancestor.name() == "pre")
+}
+
+/// The example answer is the last `<code><em>` in a part's prose; highlighted values inside
+/// example blocks are skipped.
+fn emphasized_answer(article: ElementRef) -> Option<String> {
+    article
+        .select(&selector("code > em, em > code"))
+        .filter(|element| !is_inside_pre(element))
+        .last()
+        .map(|element| element.text().collect::<String>().trim().to_string())
+}
+
+fn title(heading: &str) -> Option<String> {
+    let heading = heading
+        .trim()
+        .strip_prefix("--- Day ")?
+        .strip_suffix(" ---")?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.to_string())
+}
+
+impl Puzzle {
+    pub fn parse(html: &str) -> Result<Self, String> {
+        let document = Html::parse_document(html);
+        let articles: Vec<ElementRef> = document.select(&selector("article.day-desc")).collect();
+        let first = articles
+            .first()
+            .ok_or_else(|| String::from("the page has no puzzle description"))?;
+
+        let heading: String = first
+            .select(&selector("h2"))
+            .next()
+            .map(|heading| heading.text().collect())
+            .unwrap_or_default();
+        let title =
+            title(&heading).ok_or_else(|| format!("unexpected puzzle heading `{heading}`"))?;
+
+        let example = first.select(&selector("pre > code")).next().map(|code| {
+            let example: String = code.text().collect();
+            format!("{}\n", example.trim_end_matches('\n'))
+        });
+
+        let answers = [0, 1].map(|part| articles.get(part).copied().and_then(emphasized_answer));
+
+        let stars = document
+            .select(&selector("main > p"))
+            .filter(|paragraph| {
+                paragraph
+                    .text()
+                    .collect::<String>()
+                    .starts_with("Your puzzle answer was")
+            })
+            .count();
+
+        Ok(Self {
+            title,
+            example,
+            answers,
+            stars,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    const PAGE: &str = include_str!("../fixtures/day-01.html");
+
+    #[test]
+    fn extracts_the_example_and_answers_from_a_puzzle_page() {
+        let puzzle = Puzzle::parse(PAGE).unwrap();
+
+        assert_eq!(puzzle.title, "Historian Hysteria");
+        assert_eq!(
+            puzzle.example.as_deref(),
+            Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+        );
+        assert_eq!(
+            puzzle.answers,
+            [Some(String::from("11")), Some(String::from("31"))]
+        );
+        assert_eq!(puzzle.stars, 2);
+    }
+
+    #[test]
+    fn reads_only_part_one_before_it_is_solved() {
+        let part_one = &PAGE[..PAGE.find("<p>Your puzzle answer was").unwrap()];
+        let puzzle = Puzzle::parse(part_one).unwrap();
+
+        assert_eq!(puzzle.answers, [Some(String::from("11")), None]);
+        assert_eq!(puzzle.stars, 0);
+        assert!(Puzzle::parse("<html><body>Not found</body></html>").is_err());
+    }
+}
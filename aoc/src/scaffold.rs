@@ -0,0 +1,246 @@
+use crate::fetch::YEAR;
+use crate::puzzle::Puzzle;
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../../aoc.template.rs");
+
+fn manifest(folder: &str) -> String {
+    format!(
+        "[package]\nname = \"{folder}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+         [dependencies]\ncommon = {{ path = \"../common\" }}\n"
+    )
+}
+
+fn main_rs(crate_name: &str, solution: &str) -> String {
+    format!(
+        "use common::Part::{{Part1, Part2}};
+use common::Solution;
+use {crate_name}::{solution};
+use std::error::Error;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn Error>> {{
+    let input = fs::read_to_string(\"./input.txt\")?;
+
+    println!(\"Part 1 value: {{}}\", {solution}::solve(&input, Part1)?);
+    println!(\"Part 2 value: {{}}\", {solution}::solve(&input, Part2)?);
+
+    Ok(())
+}}
+"
+    )
+}
+
+/// The `answers.toml` entries for the example answers of `puzzle`, checked against `test.txt`.
+fn example_answers(folder: &str, day: u8, puzzle: &Puzzle) -> String {
+    let mut entries = String::new();
+
+    for (part, answer) in (1..).zip(&puzzle.answers) {
+        if let Some(answer) = answer {
+            let expected = answer.replace('\\', "\\\\").replace('"', "\\\"");
+            let _ = write!(
+                entries,
+                "\n[[answer]]\nday = {day}\npart = {part}\ninput = \"{folder}/test.txt\"\n\
+                 expected = \"{expected}\"\n"
+            );
+        }
+    }
+
+    entries
+}
+
+/// Creates `day-NN` under `root` from `aoc.template.rs`. When the puzzle page is available its
+/// first example becomes `test.txt` and its example answers are added to `answers.toml` under
+/// `root` against it; otherwise `test.txt` and `input.txt` are empty placeholders.
+pub fn scaffold(root: &Path, day: u8, puzzle: Option<&Puzzle>) -> Result<PathBuf, String> {
+    let folder = format!("day-{day:02}");
+    let dir = root.join(&folder);
+    if dir.exists() {
+        return Err(format!("`{}` already exists", dir.display()));
+    }
+
+    let solution = format!("Day{day:02}");
+    let files = [
+        ("Cargo.toml", manifest(&folder)),
+        ("src/lib.rs", TEMPLATE.replace("DayXX", &solution)),
+        ("src/main.rs", main_rs(&folder.replace('-', "_"), &solution)),
+        (
+            "test.txt",
+            puzzle
+                .and_then(|puzzle| puzzle.example.clone())
+                .unwrap_or_default(),
+        ),
+        ("input.txt", String::new()),
+    ];
+
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|error| format!("failed to create `{}`: {error}", dir.display()))?;
+    for (name, contents) in files {
+        let path = dir.join(name);
+        fs::write(&path, contents)
+            .map_err(|error| format!("failed to write `{}`: {error}", path.display()))?;
+    }
+
+    let entries = puzzle.map_or(String::new(), |puzzle| example_answers(&folder, day, puzzle));
+    if !entries.is_empty() {
+        let path = root.join("answers.toml");
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(entries.as_bytes()))
+            .map_err(|error| format!("failed to update `{}`: {error}", path.display()))?;
+    }
+
+    Ok(dir)
+}
+
+fn row_day(line: &str) -> Option<u8> {
+    line.strip_prefix('|')?
+        .split('|')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Adds or replaces the row for `day` in the README progress table and recounts the stars in
+/// its heading.
+pub fn update_progress(readme: &str, day: u8, puzzle: &Puzzle) -> String {
+    let mut lines: Vec<String> = readme.lines().map(String::from).collect();
+
+    let Some(header) = lines.iter().position(|line| line.starts_with("| Day ")) else {
+        return readme.to_string();
+    };
+    let widths: Vec<usize> = lines[header]
+        .split('|')
+        .skip(1)
+        .map(|cell| cell.chars().count().saturating_sub(2))
+        .collect();
+    let row = format!(
+        "| {:<day_width$} | {:<title_width$} | {} |",
+        day,
+        format!(
+            "[{}](https://adventofcode.com/{YEAR}/day/{day})",
+            puzzle.title
+        ),
+        "⭐".repeat(puzzle.stars),
+        day_width = widths.first().copied().unwrap_or(0),
+        title_width = widths.get(1).copied().unwrap_or(0),
+    );
+
+    let rows = header + 2
+        ..header
+            + 2
+            + lines[header + 2..]
+                .iter()
+                .take_while(|line| line.starts_with('|'))
+                .count();
+    match rows
+        .clone()
+        .find(|&index| row_day(&lines[index]) >= Some(day))
+    {
+        Some(index) if row_day(&lines[index]) == Some(day) => lines[index] = row,
+        Some(index) => lines.insert(index, row),
+        None => lines.insert(rows.end, row),
+    }
+
+    let stars: usize = lines[header..]
+        .iter()
+        .take_while(|line| line.starts_with('|'))
+        .map(|line| line.matches('⭐').count())
+        .sum();
+    if let Some(heading) = lines
+        .iter_mut()
+        .find(|line| line.starts_with("## Progress ("))
+    {
+        *heading = format!("## Progress ({stars}/50 ⭐️)");
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scaffold, update_progress};
+    use crate::puzzle::Puzzle;
+    use std::env;
+    use std::fs;
+
+    fn puzzle(title: &str, stars: usize) -> Puzzle {
+        Puzzle {
+            title: title.to_string(),
+            example: Some(String::from("3   4\n4   3\n")),
+            answers: [Some(String::from("11")), None],
+            stars,
+        }
+    }
+
+    #[test]
+    fn creates_a_day_from_the_template_once() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        let dir = scaffold(&root, 7, None).unwrap();
+
+        assert_eq!(dir, root.join("day-07"));
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day07"));
+        assert!(!lib.contains("DayXX"));
+        let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use day_07::Day07;"));
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day-07\""));
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "");
+        assert!(scaffold(&root, 7, None)
+            .unwrap_err()
+            .ends_with("already exists"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn fills_in_the_example_and_its_answers() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-example-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        let dir = scaffold(&root, 1, Some(&puzzle("Historian Hysteria", 0))).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("test.txt")).unwrap(),
+            "3   4\n4   3\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("answers.toml")).unwrap(),
+            "\n[[answer]]\nday = 1\npart = 1\ninput = \"day-01/test.txt\"\nexpected = \"11\"\n"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn keeps_the_progress_table_in_day_order() {
+        let readme = "## Progress (4/50 ⭐️)\n\n\
+                      | Day | Challenge                  | Stars |\n\
+                      |-----|----------------------------|-------|\n\
+                      | 1   | [One](https://a.b/2024/day/1) | ⭐⭐ |\n\
+                      | 3   | [Three](https://a.b/2024/day/3) | ⭐⭐ |\n";
+
+        let readme = update_progress(readme, 2, &puzzle("Red-Nosed Reports", 1));
+        let lines: Vec<&str> = readme.lines().collect();
+        assert_eq!(lines[0], "## Progress (5/50 ⭐️)");
+        assert_eq!(
+            lines[5],
+            "| 2   | [Red-Nosed Reports](https://adventofcode.com/2024/day/2) | ⭐ |"
+        );
+        assert!(lines[6].starts_with("| 3 "));
+
+        let readme = update_progress(&readme, 2, &puzzle("Red-Nosed Reports", 2));
+        assert!(readme.starts_with("## Progress (6/50 ⭐️)"));
+        assert_eq!(readme.lines().count(), 7);
+    }
+}
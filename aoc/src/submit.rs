@@ -0,0 +1,354 @@
+use common::Part;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<u64>,
+    },
+    TooSoon {
+        wait: u64,
+    },
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::Wrong { hint, .. } => {
+                write!(f, "that's not the right answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, "; it is too high"),
+                    Some(Hint::TooLow) => write!(f, "; it is too low"),
+                    None => Ok(()),
+                }
+            }
+            Verdict::TooSoon { wait } => {
+                write!(f, "an answer was submitted too recently; wait {wait}s")
+            }
+            Verdict::AlreadySolved => write!(f, "this part has already been solved"),
+        }
+    }
+}
+
+fn number(word: &str) -> Option<u64> {
+    match word {
+        "a" | "an" | "one" => Some(1),
+        "two" => Some(2),
+        "five" => Some(5),
+        "ten" => Some(10),
+        _ => word.parse().ok(),
+    }
+}
+
+/// Reads either "You have 2m 30s left to wait" or "please wait 5 minutes before trying again".
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        return left.split_whitespace().try_fold(0, |seconds, amount| {
+            let (value, unit) = amount.split_at(amount.find(|c: char| c.is_alphabetic())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(seconds + value * 3600),
+                "m" => Some(seconds + value * 60),
+                "s" => Some(seconds + value),
+                _ => None,
+            }
+        });
+    }
+
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let value = number(words.next()?)?;
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(value * 60),
+        unit if unit.starts_with("second") => Some(value),
+        _ => None,
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after posting an answer.
+    pub fn parse(html: &str) -> Result<Self, String> {
+        let document = Html::parse_document(html);
+        let selector = Selector::parse("main article").expect("selectors are valid");
+        let text: String = document
+            .select(&selector)
+            .flat_map(|article| article.text())
+            .collect();
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let lower = text.to_lowercase();
+
+        if lower.contains("that's the right answer") {
+            Ok(Verdict::Correct)
+        } else if lower.contains("that's not the right answer") {
+            let hint = if lower.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if lower.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Ok(Verdict::Wrong {
+                hint,
+                wait: parse_wait(&lower),
+            })
+        } else if lower.contains("you gave an answer too recently") {
+            Ok(Verdict::TooSoon {
+                wait: parse_wait(&lower).unwrap_or(60),
+            })
+        } else if lower.contains("don't seem to be solving the right level") {
+            Ok(Verdict::AlreadySolved)
+        } else if text.is_empty() {
+            Err(String::from("the response did not contain a verdict"))
+        } else {
+            Err(format!("unrecognised response: {text}"))
+        }
+    }
+}
+
+/// Everything already learned about one part's answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartHistory {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default)]
+    pub wrong: Vec<String>,
+    /// The largest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    /// The smallest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+}
+
+/// Previous submissions for one day, kept next to its cached input so the same wrong answer is
+/// never sent twice and the server's wait times are respected locally.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub part1: PartHistory,
+    #[serde(default)]
+    pub part2: PartHistory,
+    /// Unix time before which the server will refuse another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| format!("failed to parse `{}`: {error}", path.display())),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("failed to serialize the submission history: {error}"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("failed to create `{}`: {error}", parent.display()))?;
+        }
+        fs::write(path, json + "\n")
+            .map_err(|error| format!("failed to write `{}`: {error}", path.display()))
+    }
+
+    fn part(&self, part: Part) -> &PartHistory {
+        match part {
+            Part::Part1 => &self.part1,
+            Part::Part2 => &self.part2,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut PartHistory {
+        match part {
+            Part::Part1 => &mut self.part1,
+            Part::Part2 => &mut self.part2,
+        }
+    }
+
+    /// Refuses answers that are already known to be wrong, or that the server would refuse.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let history = self.part(part);
+
+        if let Some(correct) = &history.correct {
+            return Err(format!(
+                "part {} was already solved with {correct}",
+                part.number()
+            ));
+        }
+        if history.wrong.iter().any(|wrong| wrong == answer) {
+            return Err(format!("{answer} was already submitted and was wrong"));
+        }
+        if let Ok(value) = answer.parse::<i64>() {
+            if let Some(low) = history.too_low.filter(|&low| value <= low) {
+                return Err(format!(
+                    "{answer} cannot be right: {low} was already too low"
+                ));
+            }
+            if let Some(high) = history.too_high.filter(|&high| value >= high) {
+                return Err(format!(
+                    "{answer} cannot be right: {high} was already too high"
+                ));
+            }
+        }
+        if let Some(wait) = self.wait_until.filter(|&until| until > now) {
+            return Err(format!(
+                "the server asked to wait another {}s before the next answer",
+                wait - now
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict, now: u64) {
+        let history = self.part_mut(part);
+
+        match verdict {
+            Verdict::Correct => history.correct = Some(answer.to_string()),
+            Verdict::Wrong { hint, wait } => {
+                history.wrong.push(answer.to_string());
+                if let Ok(value) = answer.parse::<i64>() {
+                    match hint {
+                        Some(Hint::TooLow) => {
+                            history.too_low = history.too_low.max(Some(value));
+                        }
+                        Some(Hint::TooHigh) => {
+                            history.too_high =
+                                Some(history.too_high.map_or(value, |high| high.min(value)));
+                        }
+                        None => {}
+                    }
+                }
+                self.wait_until = wait.map(|wait| now + wait);
+            }
+            Verdict::TooSoon { wait } => self.wait_until = Some(now + wait),
+            Verdict::AlreadySolved => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hint, History, Verdict};
+    use crate::fetch::Client;
+    use crate::test_server;
+    use common::Part::{Part1, Part2};
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn reads_the_verdict_from_the_response() {
+        let cases = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+                 you're using the full input data.  Please wait one minute before trying again.",
+                Verdict::Wrong {
+                    hint: Some(Hint::TooHigh),
+                    wait: Some(60),
+                },
+            ),
+            (
+                "That's not the right answer.  Please wait 5 minutes before trying again.",
+                Verdict::Wrong {
+                    hint: None,
+                    wait: Some(300),
+                },
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 2m 30s left to wait.",
+                Verdict::TooSoon { wait: 150 },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::parse(&page(message)), Ok(verdict), "{message}");
+        }
+        assert!(Verdict::parse(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn refuses_repeated_and_out_of_bounds_guesses() {
+        let mut history = History::default();
+        history.record(
+            Part1,
+            "100",
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(60),
+            },
+            1_000,
+        );
+        history.record(
+            Part1,
+            "500",
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: None,
+            },
+            1_000,
+        );
+
+        assert!(history.check(Part1, "500", 2_000).is_err());
+        assert_eq!(
+            history.check(Part1, "90", 2_000),
+            Err(String::from("90 cannot be right: 100 was already too low"))
+        );
+        assert!(history.check(Part1, "600", 2_000).is_err());
+        assert_eq!(history.check(Part1, "300", 2_000), Ok(()));
+        assert_eq!(history.check(Part2, "1", 2_000), Ok(()));
+
+        history.record(Part2, "7", Verdict::TooSoon { wait: 30 }, 2_000);
+        assert_eq!(
+            history.check(Part2, "7", 2_010),
+            Err(String::from(
+                "the server asked to wait another 20s before the next answer"
+            ))
+        );
+        history.record(Part2, "7", Verdict::Correct, 2_030);
+        assert!(history.check(Part2, "8", 2_030).is_err());
+    }
+
+    #[test]
+    fn posts_the_answer_for_a_level() {
+        let server = test_server::serve(vec![(200, page("That's the right answer!"))]);
+        let client = Client::new(&server.url, Some(String::from("abc")));
+
+        let verdict = Verdict::parse(&client.submit(4, Part2, "1838").unwrap());
+
+        assert_eq!(verdict, Ok(Verdict::Correct));
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/4/answer");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+        assert_eq!(requests[0].body, "level=2&answer=1838");
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Response, Server};
+
+/// A request received by the test server.
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for adventofcode.com that answers requests with canned responses in order.
+pub struct TestServer {
+    pub url: String,
+    received: Receiver<Request>,
+    handle: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// Waits for every canned response to be served and returns the requests that were made.
+    pub fn requests(self) -> Vec<Request> {
+        self.handle.join().unwrap();
+        self.received.try_iter().collect()
+    }
+}
+
+pub fn serve<S: Into<String>>(responses: Vec<(u16, S)>) -> TestServer {
+    let responses: Vec<(u16, String)> = responses
+        .into_iter()
+        .map(|(status, response)| (status, response.into()))
+        .collect();
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let (sender, received) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        for (status, response) in responses {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string());
+
+            sender
+                .send(Request {
+                    method: request.method().to_string(),
+                    path: request.url().to_string(),
+                    cookie,
+                    body,
+                })
+                .unwrap();
+
+            let content_type = Header::from_bytes("Content-Type", "text/plain").unwrap();
+            request
+                .respond(
+                    Response::from_string(response)
+                        .with_status_code(status)
+                        .with_header(content_type),
+                )
+                .unwrap();
+        }
+    });
+
+    TestServer {
+        url,
+        received,
+        handle,
+    }
+}
@@ -0,0 +1,241 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Why a puzzle input could not be parsed, with the 1-based line and column it was found at when
+/// known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error for input that did not start with `expected`.
+    pub fn expected(expected: &str, found: &str) -> Self {
+        Self::new(format!("expected `{expected}`, found `{found}`"))
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            (Some(line), None) => write!(f, "line {line}: {}", self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A run of consecutive lines from a puzzle input, remembering where it started so that errors
+/// inside it can point at the right line of the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    first_line: usize,
+    text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            first_line: 1,
+            text: input,
+        }
+    }
+
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Every line paired with its 1-based line number in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        (self.first_line..).zip(self.text.lines())
+    }
+
+    /// Parses every line with `parse`, tagging any error with the line it came from.
+    pub fn parse_lines<T>(
+        &self,
+        mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.lines()
+            .map(|(number, line)| parse(line).map_err(|error| error.with_line(number)))
+            .collect()
+    }
+
+    /// An error pointing at the first line of this section.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(message).with_line(self.first_line)
+    }
+}
+
+impl<'a> From<&'a str> for Section<'a> {
+    fn from(input: &'a str) -> Self {
+        Self::new(input)
+    }
+}
+
+/// Splits `input` into its blank-line separated sections.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (number, line) in (1..).zip(input.split_inclusive('\n')) {
+        let is_blank = line.trim().is_empty();
+        match (start, is_blank) {
+            (None, false) => start = Some((number, offset)),
+            (Some((first_line, from)), true) => {
+                sections.push(Section {
+                    first_line,
+                    text: &input[from..offset],
+                });
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((first_line, from)) = start {
+        sections.push(Section {
+            first_line,
+            text: &input[from..],
+        });
+    }
+
+    sections
+}
+
+/// Splits `input` into exactly `N` blank-line separated sections.
+pub fn split_sections<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let sections = sections(input);
+    let count = sections.len();
+
+    sections.try_into().map_err(|_| {
+        ParseError::new(format!(
+            "expected {N} sections separated by a blank line, found {count}"
+        ))
+    })
+}
+
+/// Parses every line of `input` with `parse`, tagging any error with the line it came from.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    Section::new(input).parse_lines(parse)
+}
+
+/// Parses `text` as a number, reporting the text itself when it isn't one.
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ParseError::new(format!("expected a number, found `{}`", text.trim())))
+}
+
+/// Strips `prefix` from the start of `text`, or reports what was found instead.
+pub fn expect_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::expected(prefix, text))
+}
+
+/// Splits `text` at the first `delimiter`, or reports that it was missing.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::expected(delimiter, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_lines, parse_number, sections, split_sections, ParseError};
+
+    #[test]
+    fn reports_the_line_and_column_of_an_error() {
+        let error = ParseError::expected("X+", "Y+3")
+            .with_line(42)
+            .with_column(11);
+
+        assert_eq!(
+            error.to_string(),
+            "line 42, column 11: expected `X+`, found `Y+3`"
+        );
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+    }
+
+    #[test]
+    fn tags_line_errors_with_their_line_number() {
+        let numbers: Result<Vec<u32>, _> = parse_lines("1\n2\n3\n", parse_number);
+        let error = parse_lines("1\nx\n3\n", parse_number::<u32>).unwrap_err();
+
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+        assert_eq!(error.to_string(), "line 2: expected a number, found `x`");
+    }
+
+    #[test]
+    fn keeps_line_numbers_within_sections() {
+        let input = "a\nb\n\nc\n\n\nd\ne\n";
+        let sections = sections(input);
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[1].first_line(), 4);
+        assert_eq!(
+            sections[2].lines().collect::<Vec<_>>(),
+            [(7, "d"), (8, "e")]
+        );
+        assert_eq!(
+            sections[2]
+                .parse_lines(parse_number::<u8>)
+                .unwrap_err()
+                .line(),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn requires_the_expected_number_of_sections() {
+        assert!(split_sections::<2>("a\n\nb\n").is_ok());
+        assert_eq!(
+            split_sections::<2>("a\nb\n").unwrap_err().message(),
+            "expected 2 sections separated by a blank line, found 1"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// A position or offset on a 2D plane, with `y` growing downwards as it does in puzzle maps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point<isize> {
+    pub fn step(self, direction: impl Into<Point<isize>>) -> Self {
+        self + direction.into()
+    }
+
+    /// The four orthogonal neighbours, whether or not they lie inside any grid.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction4::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight orthogonal and diagonal neighbours, whether or not they lie inside any grid.
+    pub fn neighbors8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self.step(direction))
+    }
+}
+
+impl Point<usize> {
+    /// Steps in `direction`, or `None` when that would leave the non-negative quadrant.
+    pub fn checked_step(self, direction: impl Into<Point<isize>>) -> Option<Self> {
+        Point::<isize>::from(self).step(direction).try_into().ok()
+    }
+}
+
+macro_rules! impl_manhattan_distance {
+    ($($coordinate:ty => $distance:ty),*) => {
+        $(
+            impl Point<$coordinate> {
+                pub fn manhattan_distance(self, other: Self) -> $distance {
+                    self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+                }
+            }
+        )*
+    };
+}
+
+impl_manhattan_distance!(i32 => u32, i64 => u64, isize => usize, u32 => u32, u64 => u64, usize => usize);
+
+impl From<Point<usize>> for Point<isize> {
+    fn from(point: Point<usize>) -> Self {
+        Self::new(point.x as isize, point.y as isize)
+    }
+}
+
+impl TryFrom<Point<isize>> for Point<usize> {
+    type Error = String;
+
+    fn try_from(point: Point<isize>) -> Result<Self, Self::Error> {
+        match (usize::try_from(point.x), usize::try_from(point.y)) {
+            (Ok(x), Ok(y)) => Ok(Self::new(x, y)),
+            _ => Err(format!("{point} has a negative coordinate")),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction4; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn offset(self) -> Point<isize> {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction4 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Self::Up),
+            '>' => Ok(Self::Right),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            _ => Err(format!("`{c}` is not one of `^>v<`")),
+        }
+    }
+}
+
+impl From<Direction4> for Point<isize> {
+    fn from(direction: Direction4) -> Self {
+        direction.offset()
+    }
+}
+
+impl Display for Direction4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn offset(self) -> Point<isize> {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates anticlockwise by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction8> for Point<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8, Point};
+
+    #[test]
+    fn rotates_directions() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+    }
+
+    #[test]
+    fn parses_and_prints_arrows() {
+        let directions: Result<Vec<Direction4>, _> =
+            "^>v<".chars().map(Direction4::try_from).collect();
+
+        assert_eq!(
+            directions,
+            Ok(vec![
+                Direction4::Up,
+                Direction4::Right,
+                Direction4::Down,
+                Direction4::Left
+            ])
+        );
+        assert!(Direction4::try_from('x').is_err());
+        assert_eq!(Direction4::Down.to_string(), "v");
+    }
+
+    #[test]
+    fn steps_points_in_a_direction() {
+        let origin = Point::new(0_usize, 0);
+
+        assert_eq!(
+            origin.checked_step(Direction4::Right),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(origin.checked_step(Direction4::Up), None);
+        assert_eq!(
+            Point::new(2_isize, 2).step(Direction8::UpLeft),
+            Point::new(1, 1)
+        );
+        assert_eq!(Point::new(0_isize, 0).neighbors4().len(), 4);
+    }
+
+    #[test]
+    fn measures_manhattan_distance() {
+        assert_eq!(
+            Point::new(1_i64, -2).manhattan_distance(Point::new(-3, 4)),
+            10
+        );
+        assert_eq!(
+            Point::new(5_usize, 1).manhattan_distance(Point::new(2, 3)),
+            5
+        );
+        assert_eq!(Point::new(1, 2) * 3 + Point::new(1, 1), Point::new(4, 7));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::error::{ParseError, Section};
+use crate::geometry::{Direction4, Direction8, Point};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row. The `_signed` variants accept positions that may fall
+/// outside the grid and treat them as missing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    pub fn parse<'a>(input: impl Into<Section<'a>>) -> Result<Self, ParseError> {
+        Self::parse_with(input, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, tagging errors from `cell` with their line and column.
+    pub fn parse_with<'a>(
+        input: impl Into<Section<'a>>,
+        mut cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for (number, line) in input.into().lines() {
+            let row = (1..)
+                .zip(line.chars())
+                .map(|(column, c)| {
+                    cell(c).map_err(|error| error.with_line(number).with_column(column))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(width) = rows
+                .first()
+                .map(Vec::len)
+                .filter(|&width| width != row.len())
+            {
+                return Err(ParseError::new(format!(
+                    "expected a row of {width} cells, found {}",
+                    row.len()
+                ))
+                .with_line(number));
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Every row of a grid should have the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point<isize>) -> bool {
+        self.to_position(position).is_some()
+    }
+
+    fn index_of(&self, Point { x, y }: Point<usize>) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn get_signed(&self, position: Point<isize>) -> Option<&T> {
+        self.to_position(position)
+            .and_then(|position| self.get(position))
+    }
+
+    pub fn set(&mut self, position: Point<usize>, value: T) {
+        if let Some(cell) = self.get_mut(position) {
+            *cell = value;
+        }
+    }
+
+    /// Converts a signed position to an unsigned one if it lies inside the grid.
+    pub fn to_position(&self, position: Point<isize>) -> Option<Point<usize>> {
+        Point::try_from(position)
+            .ok()
+            .filter(|&position| self.index_of(position).is_some())
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point<usize>> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// The up to four orthogonal neighbours of `position` that lie inside the grid.
+    pub fn neighbors4(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `position` that lie inside the grid.
+    pub fn neighbors8(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Steps from `position` in `direction`, or `None` when that would leave the grid.
+    pub fn step(
+        &self,
+        position: Point<usize>,
+        direction: impl Into<Point<isize>>,
+    ) -> Option<Point<usize>> {
+        self.to_position(Point::<isize>::from(position).step(direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {position} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {position} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::error::ParseError;
+    use crate::geometry::{Direction4, Point};
+
+    const MAP: &str = "#.#\n.S.\n#.E\n";
+
+    #[test]
+    fn parses_and_renders_a_char_grid() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point::new(1, 1)], 'S');
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn finds_cells_by_value() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!(grid.find(&'E'), Some(Point::new(2, 2)));
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(2, 0), Point::new(0, 2)]
+        );
+        assert_eq!(grid.find(&'x'), None);
+    }
+
+    #[test]
+    fn only_yields_neighbors_inside_the_grid() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn treats_signed_positions_outside_the_grid_as_missing() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!(grid.get_signed(Point::new(0, -1)), None);
+        assert_eq!(grid.get_signed(Point::new(3, 0)), None);
+        assert_eq!(grid.get_signed(Point::new(1, 2)), Some(&'.'));
+        assert_eq!(grid.step(Point::new(2, 2), Direction4::Right), None);
+    }
+
+    #[test]
+    fn reports_where_a_grid_fails_to_parse() {
+        let digits = Grid::parse_with("12\n3x\n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(format!("expected a digit, found `{c}`")))
+        });
+
+        assert_eq!(
+            digits.unwrap_err().to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert_eq!(
+            Grid::parse("..\n...\n").unwrap_err().to_string(),
+            "line 2: expected a row of 2 cells, found 3"
+        );
+    }
+
+    #[test]
+    fn updates_and_maps_cells() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid.set(Point::new(0, 1), 5);
+        grid[Point::new(1, 0)] += 1;
+
+        assert_eq!(grid.map(|&cell| cell * 2).to_string(), "02\n100\n");
+    }
+}
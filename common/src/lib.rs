@@ -0,0 +1,10 @@
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod search;
+mod solution;
+
+pub use error::ParseError;
+pub use geometry::{Direction4, Direction8, Point};
+pub use grid::Grid;
+pub use solution::{Answer, Part, Solution, SolveError};
@@ -0,0 +1,339 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of exploring a graph from one or more start nodes: the cheapest cost of every
+/// reachable node and, for each node, every neighbour that reaches it on a cheapest path.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start node to `target`, including both ends.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        self.costs.get(target)?;
+
+        let mut path = vec![*target];
+        while let Some(&previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every cheapest path from a start node to `target`, each including both ends.
+    pub fn all_paths_to(&self, target: &N) -> Vec<Vec<N>> {
+        if !self.costs.contains_key(target) {
+            return vec![];
+        }
+
+        let predecessors = self.predecessors(target);
+        if predecessors.is_empty() {
+            return vec![vec![*target]];
+        }
+
+        predecessors
+            .iter()
+            .flat_map(|previous| self.all_paths_to(previous))
+            .map(|mut path| {
+                path.push(*target);
+                path
+            })
+            .collect()
+    }
+
+    /// Every node that lies on at least one cheapest path to any of `targets`.
+    pub fn nodes_on_paths_to(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut stack: Vec<N> = targets
+            .into_iter()
+            .filter(|target| self.costs.contains_key(target))
+            .collect();
+        let mut nodes = HashSet::new();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node) {
+                stack.extend_from_slice(self.predecessors(&node));
+            }
+        }
+
+        nodes
+    }
+
+    fn record(&mut self, node: N, cost: C, previous: N) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.get(&node).map(|known| cost.cmp(known)) {
+            None | Some(Ordering::Less) => {
+                self.costs.insert(node, cost);
+                self.predecessors.insert(node, vec![previous]);
+                true
+            }
+            Some(Ordering::Equal) => {
+                let predecessors = self.predecessors.entry(node).or_default();
+                if !predecessors.contains(&previous) {
+                    predecessors.push(previous);
+                }
+                false
+            }
+            Some(Ordering::Greater) => false,
+        }
+    }
+}
+
+/// Explores every node reachable from `starts` where each step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if paths.costs.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let cost = paths.costs[&node] + 1;
+        for next in neighbors(&node) {
+            if paths.record(next, cost, node) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Explores every node reachable from `starts`, where `neighbors` yields each neighbour with the
+/// non-negative cost of stepping to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::new();
+    let mut settled = HashSet::new();
+
+    for start in starts {
+        paths.costs.insert(start, C::default());
+        heap.push(Candidate::new(start, C::default()));
+    }
+
+    while let Some(Candidate { node, cost, .. }) = heap.pop() {
+        if !settled.insert(node) {
+            continue;
+        }
+
+        for (next, step) in neighbors(&node) {
+            if paths.record(next, cost + step, node) {
+                heap.push(Candidate::new(next, cost + step));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Finds one cheapest path from `start` to the first node satisfying `is_goal`, guided by a
+/// `heuristic` that must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::from([Candidate {
+        node: start,
+        cost: C::default(),
+        priority: heuristic(&start),
+    }]);
+    let mut settled = HashSet::new();
+    paths.costs.insert(start, C::default());
+
+    while let Some(Candidate { node, cost, .. }) = heap.pop() {
+        if is_goal(&node) {
+            return paths.path_to(&node).map(|path| (path, cost));
+        }
+        if !settled.insert(node) {
+            continue;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if paths.record(next, next_cost, node) {
+                heap.push(Candidate {
+                    node: next,
+                    cost: next_cost,
+                    priority: next_cost + heuristic(&next),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// A node waiting in the priority queue, ordered so that the lowest `priority` pops first.
+struct Candidate<N, C> {
+    node: N,
+    cost: C,
+    priority: C,
+}
+
+impl<N, C: Copy> Candidate<N, C> {
+    fn new(node: N, cost: C) -> Self {
+        Self {
+            node,
+            cost,
+            priority: cost,
+        }
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+.....
+.#.#.
+.....
+";
+
+    fn open_neighbors(grid: &Grid<char>, position: Point<usize>) -> Vec<Point<usize>> {
+        grid.neighbors4(position)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_distances_and_paths_with_bfs() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let paths = bfs([Point::new(0, 0)], |&position| {
+            open_neighbors(&grid, position)
+        });
+
+        assert_eq!(paths.cost(&Point::new(4, 2)), Some(6));
+        assert_eq!(
+            paths.path_to(&Point::new(0, 2)).map(|path| path.len()),
+            Some(3)
+        );
+        assert_eq!(paths.cost(&Point::new(1, 1)), None);
+        assert_eq!(paths.path_to(&Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn enumerates_every_shortest_path() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let paths = bfs([Point::new(0, 0)], |&position| {
+            open_neighbors(&grid, position)
+        });
+        let target = Point::new(2, 2);
+
+        assert_eq!(paths.all_paths_to(&target).len(), 2);
+        assert_eq!(paths.nodes_on_paths_to([target]).len(), 8);
+    }
+
+    #[test]
+    fn prefers_cheaper_edges_with_dijkstra() {
+        let graph = |node: &char| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra(['a'], graph);
+
+        assert_eq!(paths.cost(&'c'), Some(2));
+        assert_eq!(paths.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(paths.predecessors(&'c'), ['b']);
+    }
+
+    #[test]
+    fn matches_dijkstra_with_astar() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let end = Point::new(4, 2);
+        let neighbors = |position: &Point<usize>| {
+            open_neighbors(&grid, *position)
+                .into_iter()
+                .map(|next| (next, 1))
+        };
+
+        let (path, cost) = astar(
+            Point::new(0, 0),
+            neighbors,
+            |position| position.manhattan_distance(end),
+            |&position| position == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path.len(), 7);
+        assert_eq!(
+            dijkstra([Point::new(0, 0)], neighbors).cost(&end),
+            Some(cost)
+        );
+        assert_eq!(astar(Point::new(0, 0), neighbors, |_| 0, |_| false), None);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::error::ParseError;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1 => Ok(Part::Part1),
+            2 => Ok(Part::Part2),
+            _ => Err(format!("part must be 1 or 2, got {number}")),
+        }
+    }
+}
+
+/// Why a day has no answer: either its input did not parse, or one part failed on input that did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Part { part: Part, message: String },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{error}"),
+            SolveError::Part { part, message } => write!(f, "part {}: {message}", part.number()),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+/// What a part returns. Parts that always have an answer return it directly; parts that can fail
+/// on input that parsed return a `Result` whose error says why.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer {
+    ($($answer:ty),*) => {
+        $(
+            impl Answer for $answer {
+                fn into_answer(self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String);
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map(|answer| answer.to_string())
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// A single day's puzzle, split into parsing and the two parts so that tooling can drive every day
+/// the same way.
+pub trait Solution {
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    fn solve(input: &str, part: Part) -> Result<String, SolveError> {
+        let input = Self::parse(input)?;
+
+        match part {
+            Part::Part1 => Self::part1(&input).into_answer(),
+            Part::Part2 => Self::part2(&input).into_answer(),
+        }
+        .map_err(|message| SolveError::Part { part, message })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{parse_lines, parse_number, ParseError};
+    use crate::{Part, Solution, SolveError};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse_lines(input, parse_number)
+        }
+
+        fn part1(numbers: &Self::Input) -> i32 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Self::Input) -> String {
+            format!("{} numbers", numbers.len())
+        }
+    }
+
+    #[test]
+    fn solves_each_part_through_the_trait() {
+        assert_eq!(Sum::solve("1\n2\n3", Part::Part1), Ok("6".to_string()));
+        assert_eq!(
+            Sum::solve("1\n2\n3", Part::Part2),
+            Ok("3 numbers".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors_instead_of_solving() {
+        let error = Sum::solve("1\ntwo\n3", Part::Part1).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2: expected a number, found `two`");
+    }
+
+    struct Mean;
+
+    impl Solution for Mean {
+        type Input = Vec<i32>;
+        type Answer1 = Result<i32, &'static str>;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse_lines(input, parse_number)
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<i32, &'static str> {
+            match numbers.len() {
+                0 => Err("there are no numbers"),
+                count => Ok(numbers.iter().sum::<i32>() / count as i32),
+            }
+        }
+
+        fn part2(numbers: &Self::Input) -> usize {
+            numbers.len()
+        }
+    }
+
+    #[test]
+    fn reports_parts_that_fail_on_parsed_input() {
+        assert_eq!(Mean::solve("2\n4", Part::Part1), Ok("3".to_string()));
+        assert_eq!(
+            Mean::solve("", Part::Part1),
+            Err(SolveError::Part {
+                part: Part::Part1,
+                message: "there are no numbers".to_string()
+            })
+        );
+        assert_eq!(
+            Mean::solve("", Part::Part1).unwrap_err().to_string(),
+            "part 1: there are no numbers"
+        );
+        assert_eq!(Mean::solve("", Part::Part2), Ok("0".to_string()));
+    }
+
+    #[test]
+    fn converts_part_numbers() {
+        assert_eq!(Part::try_from(1), Ok(Part::Part1));
+        assert_eq!(Part::try_from(2), Ok(Part::Part2));
+        assert!(Part::try_from(3).is_err());
+        assert_eq!(Part::Part2.number(), 2);
+    }
+}
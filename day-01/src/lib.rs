@@ -0,0 +1,58 @@
+use common::error::{parse_lines, parse_number};
+use common::{ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let pairs: Vec<(i32, i32)> = parse_lines(input, |line| {
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [first, second] => Ok((parse_number(first)?, parse_number(second)?)),
+                _ => Err(ParseError::new(format!(
+                    "expected two location IDs, found `{line}`"
+                ))),
+            }
+        })?;
+
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1(lists: &Self::Input) -> i32 {
+        let mut first = lists.0.clone();
+        let mut second = lists.1.clone();
+
+        first.sort_unstable();
+        second.sort_unstable();
+
+        first
+            .iter()
+            .zip(second.iter())
+            .map(|(a, b)| (b - a).abs())
+            .sum()
+    }
+
+    fn part2(lists: &Self::Input) -> i32 {
+        let counts_1: HashMap<_, _> = lists.0.iter().fold(HashMap::new(), |mut map, &num| {
+            *map.entry(num).or_insert(0) += 1;
+            map
+        });
+
+        let counts_2: HashMap<_, _> = lists.1.iter().fold(HashMap::new(), |mut map, &num| {
+            *map.entry(num).or_insert(0) += 1;
+            map
+        });
+
+        counts_1
+            .iter()
+            .map(|(&num, &count1)| {
+                let count2 = counts_2.get(&num).unwrap_or(&0);
+                num * count1 * count2
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,92 @@
+use common::error::{parse_lines, parse_number};
+use common::{ParseError, Solution};
+
+#[derive(PartialEq, Debug)]
+enum LevelStatus {
+    Increasing,
+    Decreasing,
+    Invalid,
+}
+
+fn parse_lines_to_vec(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_lines(input, |line| {
+        line.split_whitespace()
+            .map(parse_number)
+            .collect::<Result<Vec<i32>, _>>()
+    })
+}
+
+fn get_status_for_level(sequence: &[i32]) -> LevelStatus {
+    if sequence.len() < 2 {
+        return LevelStatus::Invalid;
+    }
+
+    let first_diff = sequence[1] - sequence[0];
+    let level_status = if first_diff > 0 {
+        LevelStatus::Increasing
+    } else {
+        LevelStatus::Decreasing
+    };
+
+    for window in sequence.windows(2) {
+        let diff = window[1] - window[0];
+        let is_invalid = !(-3..=3).contains(&diff) || diff == 0 
+            || (diff < 0 && level_status == LevelStatus::Increasing)
+            || (diff > 0 && level_status == LevelStatus::Decreasing);
+
+        if is_invalid {
+            return LevelStatus::Invalid;
+        }
+    }
+
+    level_status
+}
+
+fn get_status_for_level_with_problem_dampener(sequence: &[i32]) -> LevelStatus {
+    if sequence.len() < 2 {
+        return LevelStatus::Invalid;
+    }
+
+    if get_status_for_level(sequence) != LevelStatus::Invalid {
+        return get_status_for_level(sequence);
+    }
+
+    for i in 0..sequence.len() {
+        let mut test_sequence = sequence.to_vec();
+        test_sequence.remove(i);
+
+        let updated_sequence = get_status_for_level(&test_sequence);
+
+        if updated_sequence != LevelStatus::Invalid {
+            return updated_sequence;
+        }
+    }
+
+    LevelStatus::Invalid
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines_to_vec(input)
+    }
+
+    fn part1(lines: &Self::Input) -> i32 {
+        lines
+            .iter()
+            .filter(|line| get_status_for_level(line) != LevelStatus::Invalid)
+            .count() as i32
+    }
+
+    fn part2(lines: &Self::Input) -> i32 {
+        lines
+            .iter()
+            .filter(|line| get_status_for_level_with_problem_dampener(line) != LevelStatus::Invalid)
+            .count() as i32
+    }
+}
@@ -0,0 +1,88 @@
+use common::{ParseError, Solution};
+use regex::Regex;
+
+fn get_instructions_from_string(input: &str) -> Result<Vec<(i32, i32)>, String> {
+    let re = Regex::new(r"mul\(\s*(\d+)\s*,\s*(\d+)\s*\)").unwrap();
+
+    re.captures_iter(input).map(|c| {
+        let first_digit = c.get(1).unwrap().as_str();
+        let second_digit = c.get(2).unwrap().as_str();
+
+        match (first_digit.parse::<i32>(), second_digit.parse::<i32>()) {
+            (Ok(x), Ok(y)) => Ok((x, y)),
+            _ => Err("Invalid input".to_string())
+        }
+    }).collect()
+}
+
+fn get_instructions_with_conditional_statements_from_string(
+    input: &str,
+    initial_instructions_enabled: bool
+) -> Result<(Vec<i32>, bool), String> {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)|don't\(\)|do\(\)").unwrap();
+    
+    let results = re.captures_iter(input)
+        .try_fold((Vec::new(), initial_instructions_enabled), |(mut results, instructions_enabled), caps| {
+            let instruction = caps.get(0).unwrap().as_str();
+            
+            match instruction {
+                s if s.starts_with("mul") => {
+                    let (first, second) = (caps.get(1).unwrap(), caps.get(2).unwrap());
+                    match (first.as_str().parse::<i32>(), second.as_str().parse::<i32>()) {
+                        (Ok(x), Ok(y)) if instructions_enabled => {
+                            results.push(x * y);
+                            Ok((results, instructions_enabled))
+                        },
+                        (Ok(_), Ok(_)) => Ok((results, instructions_enabled)),
+                        _ => Err("Invalid input".to_string())
+                    }
+                },
+                "don't()" => Ok((results, false)),
+                "do()" => Ok((results, true)),
+                _ => Err("Invalid input".to_string())
+            }
+        });
+
+    results
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> i32 {
+        lines
+            .iter()
+            .filter_map(|line| {
+                match get_instructions_from_string(line) {
+                    Ok(x) => Some(x.iter().map(|(x, y)| x * y).sum::<i32>()),
+                    Err(_) => None,
+                }
+            })
+            .sum()
+    }
+
+    fn part2(lines: &Self::Input) -> i32 {
+        let mut final_sum = 0;
+        let mut instructions_enabled = true;
+
+        for line in lines {
+            match get_instructions_with_conditional_statements_from_string(line, instructions_enabled) {
+                Ok((results, final_instructions_enabled)) => {
+                    final_sum += results.iter().sum::<i32>();
+                    instructions_enabled = final_instructions_enabled;
+                }
+                Err(_) => continue,
+            }
+        }
+
+        final_sum
+    }
+}
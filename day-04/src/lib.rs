@@ -0,0 +1,68 @@
+use common::{Direction8, Grid, ParseError, Point, Solution};
+
+fn get_word_count(grid: &Grid<char>, position: Point<usize>, search_word: &str) -> usize {
+    let mut count: usize = 0;
+
+    for direction in Direction8::ALL {
+        let mut word: Vec<char> = vec!['.'; search_word.len()];
+        for (i, c) in word.iter_mut().enumerate() {
+            let position = Point::<isize>::from(position) + direction.offset() * i as isize;
+            *c = *grid.get_signed(position).unwrap_or(&'.');
+        }
+        if word.iter().collect::<String>() == search_word {
+            count += 1
+        }
+    }
+
+    count
+}
+
+fn count_words(grid: &Grid<char>, search_word: &str) -> usize {
+    grid.positions()
+        .map(|position| get_word_count(grid, position, search_word))
+        .sum()
+}
+
+fn count_x_formations(grid: &Grid<char>, search_word: &str) -> i32 {
+    let mut x_formation_count = 0;
+    for position in grid.positions() {
+        if get_is_x_formation(grid, position.into(), search_word) {
+            x_formation_count += 1
+        }
+    }
+
+    x_formation_count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        count_words(grid, "XMAS")
+    }
+
+    fn part2(grid: &Self::Input) -> i32 {
+        count_x_formations(grid, "MAS")
+    }
+}
+
+fn get_is_x_formation(chars: &Grid<char>, position: Point<isize>, search_word: &str) -> bool {
+    let char_at = |direction: Direction8| *chars.get_signed(position.step(direction)).unwrap_or(&'.');
+    let c = *chars.get_signed(position).unwrap_or(&'.');
+    let tl = char_at(Direction8::UpLeft);
+    let tr = char_at(Direction8::UpRight);
+    let bl = char_at(Direction8::DownLeft);
+    let br = char_at(Direction8::DownRight);
+
+    [format!("{tl}{c}{br}"), format!("{bl}{c}{tr}")]
+        .iter()
+        .all(|word| word == search_word || *word == search_word.chars().rev().collect::<String>())
+}
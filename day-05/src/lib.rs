@@ -0,0 +1,134 @@
+use common::error::{parse_number, split_once, split_sections};
+use common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+fn build_dependency_graph(rules: &[(i32, i32)]) -> HashMap<i32, HashSet<i32>> {
+    let mut graph: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+    for &(before, after) in rules {
+        graph.entry(before).or_default();
+        graph.entry(after).or_default().insert(before);
+    }
+
+    graph
+}
+
+fn parse_page_number_rules(input: &str) -> Result<(i32, i32), ParseError> {
+    let (before, after) = split_once(input, "|")?;
+
+    Ok((parse_number(before)?, parse_number(after)?))
+}
+
+fn get_middle_page(sequence: &[i32]) -> i32 {
+    let middle_index = (sequence.len() - 1) / 2;
+    sequence[middle_index]
+}
+
+fn is_valid_update_sequence(sequence: &[i32], graph: &HashMap<i32, HashSet<i32>>) -> bool {
+    for (index, &page) in sequence.iter().enumerate() {
+        let pages_seen: HashSet<i32> = sequence[..index].iter().copied().collect();
+
+        if let Some(prerequisites) = graph.get(&page) {
+            let relevant_prerequisites: HashSet<_> = prerequisites
+                .iter()
+                .filter(|prerequisite| sequence.contains(prerequisite))
+                .copied()
+                .collect();
+
+            if !relevant_prerequisites.is_subset(&pages_seen) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn reorder_sequence(sequence: &[i32], graph: &HashMap<i32, HashSet<i32>>) -> Vec<i32> {
+    let mut reordered_sequence: Vec<i32> = sequence.to_vec();
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for i in 0..reordered_sequence.len() {
+            let page = reordered_sequence[i];
+            if let Some(prerequisites) = graph.get(&page) {
+                let mut relevant_prerequisites: Vec<_> = prerequisites
+                    .iter()
+                    .filter(|&&prereq| reordered_sequence.contains(&prereq))
+                    .copied()
+                    .collect();
+
+                relevant_prerequisites.sort();
+
+                for &prerequisite in &relevant_prerequisites {
+                    let prereq_pos = reordered_sequence
+                        .iter()
+                        .position(|&p| p == prerequisite)
+                        .unwrap();
+                    if prereq_pos > i {
+                        reordered_sequence.remove(prereq_pos);
+                        reordered_sequence.insert(i, prerequisite);
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    reordered_sequence
+}
+
+pub struct PrintQueue {
+    graph: HashMap<i32, HashSet<i32>>,
+    updates: Vec<Vec<i32>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [rules, updates] = split_sections(input)?;
+
+        let parsed_rules = rules.parse_lines(parse_page_number_rules)?;
+
+        let parsed_updates = updates.parse_lines(|line| {
+            line.split(',')
+                .map(parse_number)
+                .collect::<Result<Vec<i32>, _>>()
+        })?;
+
+        Ok(PrintQueue {
+            graph: build_dependency_graph(&parsed_rules),
+            updates: parsed_updates,
+        })
+    }
+
+    fn part1(queue: &Self::Input) -> i32 {
+        queue
+            .updates
+            .iter()
+            .filter(|sequence| is_valid_update_sequence(sequence, &queue.graph))
+            .map(|sequence| get_middle_page(sequence))
+            .sum()
+    }
+
+    fn part2(queue: &Self::Input) -> i32 {
+        queue
+            .updates
+            .iter()
+            .filter_map(|sequence| {
+                let is_valid = is_valid_update_sequence(sequence, &queue.graph);
+                if is_valid {
+                    return None;
+                }
+                let reordered_sequence = reorder_sequence(sequence, &queue.graph);
+                Some(get_middle_page(reordered_sequence.as_slice()))
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,94 @@
+use common::{Direction4, Grid, ParseError, Point, Solution};
+
+/// Neither part has an answer when the guard never leaves the map.
+const LOOPING: &str = "the guard walks in a loop and never leaves the map";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid<char>;
+    type Answer1 = Result<usize, &'static str>;
+    type Answer2 = Result<usize, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input)?;
+        if map.find_all(&'^').count() != 1 {
+            return Err(ParseError::new("expected the map to contain exactly one guard `^`"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize, &'static str> {
+        Ok(simulate(map).ok_or(LOOPING)?.len())
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize, &'static str> {
+        // The guard would notice an obstruction placed on their starting position.
+        let guard = map.find(&'^');
+        Ok(simulate(map)
+            .ok_or(LOOPING)?
+            .into_iter()
+            .filter(|&position| Some(position) != guard)
+            .filter(|&position| {
+                let mut obstructed_map = map.clone();
+                obstructed_map[position] = '#';
+                simulate(&obstructed_map).is_none()
+            })
+            .count())
+    }
+}
+
+fn simulate(map: &Grid<char>) -> Option<Vec<Point<usize>>> {
+    let mut position = map.find(&'^').expect("Day06::parse checks for exactly one guard");
+    let mut direction = Direction4::Up;
+    let mut distinct_positions = Grid::filled(map.width(), map.height(), [false; 4]);
+
+    loop {
+        if distinct_positions[position][direction as usize] {
+            return None;
+        }
+
+        distinct_positions[position][direction as usize] = true;
+
+        match map.step(position, direction) {
+            None => {
+                return Some(get_visited_positions(&distinct_positions));
+            }
+            Some(next_position) if map[next_position] == '#' => direction = direction.turn_right(),
+            Some(next_position) => position = next_position,
+        }
+    }
+}
+
+fn get_visited_positions(distinct_positions: &Grid<[bool; 4]>) -> Vec<Point<usize>> {
+    distinct_positions
+        .iter()
+        .filter(|(_, directions_seen)| directions_seen.iter().any(|&dir_seen| dir_seen))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day06;
+    use common::Part::{Part1, Part2};
+    use common::Solution;
+
+    #[test]
+    fn rejects_maps_without_exactly_one_guard() {
+        let error = Day06::parse("..#\n...\n").err().unwrap();
+        assert_eq!(error.to_string(), "expected the map to contain exactly one guard `^`");
+        assert!(Day06::parse("^.#\n..^\n").is_err());
+    }
+
+    #[test]
+    fn reports_a_guard_walking_in_a_loop() {
+        let input = ".#..\n...#\n#^..\n..#.\n";
+        let message = "the guard walks in a loop and never leaves the map";
+
+        let error = Day06::solve(input, Part1).unwrap_err();
+        assert_eq!(error.to_string(), format!("part 1: {message}"));
+        let error = Day06::solve(input, Part2).unwrap_err();
+        assert_eq!(error.to_string(), format!("part 2: {message}"));
+    }
+}
@@ -0,0 +1,86 @@
+use common::error::{parse_lines, parse_number, split_once};
+use common::{ParseError, Part, Solution};
+use std::collections::HashMap;
+use crate::Part::{Part1, Part2};
+
+fn concatenation_operator(first_number: i64, second_number: i64) -> i64 {
+    format!("{}{}", first_number, second_number)
+        .parse()
+        .expect("Should have been able to parse the joined number")
+}
+
+fn get_is_calibrated(numbers: &[i64], target: i64, part: &Part) -> bool {
+    if numbers.len() == 1 {
+        return numbers[0] == target;
+    }
+
+    let mut cache: HashMap<(usize, i64), bool> = HashMap::new();
+
+    fn calibrate(
+        pos: usize,
+        current: i64,
+        numbers: &[i64],
+        target: i64,
+        cache: &mut HashMap<(usize, i64), bool>,
+        part: &Part,
+    ) -> bool {
+        if pos == numbers.len() {
+            return current == target;
+        }
+
+        if let Some(&result) = cache.get(&(pos, current)) {
+            return result;
+        }
+
+        let num = numbers[pos];
+        let result = if part == &Part1 {
+            calibrate(pos + 1, current + num, numbers, target, cache, part)
+                || calibrate(pos + 1, current * num, numbers, target, cache, part)
+        } else {
+            calibrate(pos + 1, current + num, numbers, target, cache, part)
+                || calibrate(pos + 1, current * num, numbers, target, cache, part)
+                || calibrate(pos + 1, concatenation_operator(current, num), numbers, target, cache, part)
+        };
+
+        cache.insert((pos, current), result);
+        result
+    }
+
+    calibrate(1, numbers[0], numbers, target, &mut cache, part)
+}
+
+fn total_calibration_result(equations: &[(i64, Vec<i64>)], part: Part) -> i64 {
+    equations
+        .iter()
+        .filter_map(|(target, numbers)| get_is_calibrated(numbers, *target, &part).then_some(target))
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let (test_value, numbers) = split_once(line, ":")?;
+            Ok((
+                parse_number(test_value)?,
+                numbers
+                    .split_whitespace()
+                    .map(parse_number)
+                    .collect::<Result<Vec<i64>, _>>()?,
+            ))
+        })
+    }
+
+    fn part1(equations: &Self::Input) -> i64 {
+        total_calibration_result(equations, Part1)
+    }
+
+    fn part2(equations: &Self::Input) -> i64 {
+        total_calibration_result(equations, Part2)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::Part::{Part1, Part2};
+use common::{Grid, ParseError, Part, Point, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+fn is_collinear(p1: Point<usize>, p2: Point<usize>, p3: Point<usize>) -> bool {
+    let (p1, p2, p3) = (Point::<isize>::from(p1), Point::from(p2), Point::from(p3));
+
+    let v1 = p2 - p1;
+    let v2 = p3 - p1;
+
+    v1.x * v2.y == v1.y * v2.x
+}
+
+fn count_unique_antinodes(map: &Grid<char>, part: Part) -> usize {
+    let mut antennas: HashMap<char, Vec<Point<usize>>> = HashMap::new();
+
+    for (position, &c) in map.iter() {
+        if c != '.' {
+            antennas.entry(c).or_default().push(position)
+        }
+    }
+
+    let mut antinodes: HashSet<Point<usize>> = HashSet::new();
+
+    if part == Part1 {
+        for antenna in antennas.values() {
+            for &p1 in antenna.iter() {
+                for &p2 in antenna.iter() {
+                    if p1 == p2 {
+                        continue;
+                    }
+                    let antinode = Point::<isize>::from(p2) * 2 - Point::from(p1);
+                    if let Some(antinode) = map.to_position(antinode) {
+                        antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+    } else {
+        for current_point in map.positions() {
+            for antenna_points in antennas.values() {
+                for (p1, p2) in antenna_points.iter().tuple_combinations::<(_, _)>() {
+                    if is_collinear(*p1, *p2, current_point) {
+                        antinodes.insert(current_point);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        count_unique_antinodes(map, Part1)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        count_unique_antinodes(map, Part2)
+    }
+}
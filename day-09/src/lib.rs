@@ -0,0 +1,156 @@
+use common::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct DiskEntry {
+    id: u64,
+    size: usize,
+}
+
+impl DiskEntry {
+    fn new_file(id: u64, size: usize) -> Self {
+        Self { id, size }
+    }
+
+    fn new_empty_space(size: usize) -> Self {
+        Self { id: u64::MAX, size }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.id == u64::MAX
+    }
+}
+
+fn parse_disk_map(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new("expected a disk map, found empty input"))?;
+
+    (1..)
+        .zip(line.chars())
+        .map(|(column, c)| {
+            c.to_digit(10).map(|digit| digit as usize).ok_or_else(|| {
+                ParseError::new(format!("expected a digit, found `{c}`"))
+                    .with_line(1)
+                    .with_column(column)
+            })
+        })
+        .collect()
+}
+
+fn read_filesystem(numbers: &[usize]) -> Vec<u64> {
+    let mut disk = Vec::new();
+
+    for (next_index, chunk) in (0u64..).zip(numbers.chunks(2)) {
+        disk.extend(std::iter::repeat_n(next_index, chunk[0]));
+
+        if chunk.len() > 1 {
+            disk.extend(std::iter::repeat_n(u64::MAX, chunk[1]));
+        }
+    }
+
+    disk
+}
+
+fn read_filesystem_for_file_size(numbers: &[usize]) -> Vec<DiskEntry> {
+    let mut disk = Vec::new();
+
+    for (next_file_id, chunk) in (0u64..).zip(numbers.chunks(2)) {
+        let file_size = chunk[0];
+        disk.push(DiskEntry::new_file(next_file_id, file_size));
+
+        if chunk.len() > 1 {
+            let space_size = chunk[1];
+            disk.push(DiskEntry::new_empty_space(space_size));
+        }
+    }
+    disk
+}
+
+fn defragment(disk: &mut [u64]) {
+    for i in (1..disk.len()).rev() {
+        let id = disk[i];
+        if id == u64::MAX {
+            continue;
+        }
+
+        if let Some(empty_pos) = disk[..i].iter().position(|&x| x == u64::MAX) {
+            disk[empty_pos] = id;
+            disk[i] = u64::MAX;
+        }
+    }
+}
+
+fn defragment_using_file_size(disk: &mut Vec<DiskEntry>) {
+    let mut current_pos = disk.len() - 1;
+    
+    while current_pos > 0 {
+        let current_entry = disk[current_pos];
+        
+        if current_entry.is_empty() {
+            current_pos -= 1;
+            continue;
+        }
+
+        if let Some(target_pos) = find_suitable_empty_space(disk, current_pos, current_entry.size) {
+            move_file_to_space(disk, current_pos, target_pos);
+        }
+        
+        current_pos -= 1;
+    }
+}
+
+fn find_suitable_empty_space(disk: &[DiskEntry], current_pos: usize, needed_size: usize) -> Option<usize> {
+    disk[..current_pos]
+        .iter()
+        .position(|entry| entry.is_empty() && entry.size >= needed_size)
+}
+
+fn move_file_to_space(disk: &mut Vec<DiskEntry>, from_pos: usize, to_pos: usize) {
+    let file = disk[from_pos];
+    let empty_space_size = disk[to_pos].size;
+
+    disk[to_pos] = DiskEntry::new_file(file.id, file.size);
+    disk[from_pos] = DiskEntry::new_empty_space(file.size);
+
+    if file.size < empty_space_size {
+        disk.insert(
+            to_pos + 1, 
+            DiskEntry::new_empty_space(empty_space_size - file.size)
+        );
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<usize>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_disk_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        let mut disk = read_filesystem(input);
+        defragment(&mut disk);
+
+        disk.iter()
+            .enumerate()
+            .filter(|(_, &id)| id != u64::MAX)
+            .map(|(i, &id)| id * i as u64)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let mut disk = read_filesystem_for_file_size(input);
+        defragment_using_file_size(&mut disk);
+
+        disk.iter()
+            .flat_map(|&disk_entry| (0..disk_entry.size).map(move |_| disk_entry.id))
+            .enumerate()
+            .map(|(i, id)| if id != u64::MAX { id * i as u64 } else { 0 })
+            .sum()
+    }
+}
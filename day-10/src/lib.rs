@@ -0,0 +1,67 @@
+use common::search::{bfs, Paths};
+use common::{Grid, ParseError, Point, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(format!("expected a height, found `{c}`")))
+        })
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        get_trailheads(map)
+            .iter()
+            .map(|&trailhead| calculate_score(map, trailhead))
+            .sum()
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        get_trailheads(map)
+            .iter()
+            .map(|&trailhead| get_rating(map, trailhead))
+            .sum()
+    }
+}
+
+fn explore_trails(map: &Grid<u32>, trailhead: Point<usize>) -> Paths<Point<usize>, usize> {
+    bfs([trailhead], |&position| {
+        map.neighbors4(position)
+            .filter(|&next| map[next] == map[position] + 1)
+            .collect::<Vec<_>>()
+    })
+}
+
+fn summits(map: &Grid<u32>, trails: &Paths<Point<usize>, usize>) -> Vec<Point<usize>> {
+    trails
+        .costs()
+        .keys()
+        .copied()
+        .filter(|&position| map[position] == 9)
+        .collect()
+}
+
+// Every trail climbs one step at a time, so all trails to a summit are equally short and each
+// distinct trail is one of its shortest paths.
+fn get_rating(map: &Grid<u32>, trailhead: Point<usize>) -> usize {
+    let trails = explore_trails(map, trailhead);
+
+    summits(map, &trails)
+        .iter()
+        .map(|summit| trails.all_paths_to(summit).len())
+        .sum()
+}
+
+fn get_trailheads(map: &Grid<u32>) -> Vec<Point<usize>> {
+    map.find_all(&0).collect()
+}
+
+fn calculate_score(map: &Grid<u32>, trailhead: Point<usize>) -> usize {
+    summits(map, &explore_trails(map, trailhead)).len()
+}
@@ -0,0 +1,108 @@
+use common::error::parse_number;
+use common::{ParseError, Part, Solution};
+use std::collections::HashMap;
+use crate::Part::{Part1, Part2};
+
+#[derive(Debug)]
+struct StoneTransformer {
+    stones: HashMap<u64, u64>,
+}
+
+impl StoneTransformer {
+    fn new(initial_stones: &[u64]) -> Self {
+        let stones = initial_stones.iter().map(|&n| (n, 1)).collect();
+
+        Self { stones }
+    }
+
+    fn process_stone(n: u64, cache: u64) -> Vec<(u64, u64)> {
+        if n == 0 {
+            vec![(1, cache)]
+        } else {
+            let digits = n.ilog10() + 1;
+            if digits.is_multiple_of(2) {
+                let power = 10u64.pow(digits / 2);
+                vec![
+                    (n % power, cache),
+                    (n / power, cache),
+                ]
+            } else {
+                vec![(n * MULTIPLIER, cache)]
+            }
+        }
+    }
+
+    fn transform(&mut self) {
+        let mut new_stones: HashMap<u64, u64> = HashMap::new();
+        for (&n, &cache) in &self.stones {
+            for (new_n, new_cache) in Self::process_stone(n, cache) {
+                *new_stones.entry(new_n).or_default() += new_cache;
+            }
+        }
+        self.stones = new_stones;
+    }
+
+    fn get_total(&self) -> u64 {
+        self.stones.values().sum()
+    }
+}
+
+const MULTIPLIER: u64 = 2024;
+
+fn count_stones(stones: &[u64], blink_count: i64, part: Part) -> usize {
+    if part == Part1 {
+        transform_stones(stones.to_vec(), blink_count).len()
+    } else {
+        let mut transformer = StoneTransformer::new(stones);
+    
+        (0..blink_count).for_each(|_| transformer.transform());
+
+        transformer.get_total() as usize
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.split_whitespace().map(parse_number).collect()
+    }
+
+    fn part1(stones: &Self::Input) -> usize {
+        count_stones(stones, 25, Part1)
+    }
+
+    fn part2(stones: &Self::Input) -> usize {
+        count_stones(stones, 75, Part2)
+    }
+}
+
+fn process_stone(stone: u64) -> Vec<u64> {
+    if stone == 0 {
+        vec![1]
+    } else {
+        let digits = stone.to_string();
+        if digits.len().is_multiple_of(2) {
+            let mid = digits.len() / 2;
+            let (first, second) = digits.split_at(mid);
+            vec![
+                first.parse().expect("Invalid first half"),
+                second.parse().expect("Invalid second half")
+            ]
+        } else {
+            vec![stone * 2024]
+        }
+    }
+}
+
+fn transform_stones(initial_stones: Vec<u64>, blink_count: i64) -> Vec<u64> {
+    (0..blink_count).fold(initial_stones, |stones, _| {
+        stones.into_iter()
+            .flat_map(process_stone)
+            .collect()
+    })
+}
@@ -0,0 +1,185 @@
+use crate::Part::{Part1, Part2};
+use common::{Direction4, Grid, ParseError, Part, Point, Solution};
+use std::collections::HashSet;
+
+type Edge = (Point<isize>, Point<isize>);
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(garden: &Self::Input) -> usize {
+        calculate_total_price(garden, Part1)
+    }
+
+    fn part2(garden: &Self::Input) -> usize {
+        calculate_total_price(garden, Part2)
+    }
+}
+
+fn calculate_total_price(garden: &Grid<char>, part: Part) -> usize {
+    let mut total = 0;
+    let mut explored = Grid::filled(garden.width(), garden.height(), false);
+
+    for plot in garden.positions() {
+        if explored[plot] {
+            continue;
+        }
+
+        let (area, perimeter) = match part {
+            Part1 => find_plot(garden, &mut explored, plot),
+            Part2 => find_plot_with_reduced_fencing(garden, &mut explored, plot),
+        };
+        total += area * perimeter;
+    }
+
+    total
+}
+
+fn find_plot(
+    garden: &Grid<char>,
+    explored: &mut Grid<bool>,
+    position: Point<usize>,
+) -> (usize, usize) {
+    let visited = explore_connected_positions(garden, explored, position);
+    let perimeter = calculate_perimeter(&visited);
+    (visited.len(), perimeter)
+}
+
+fn explore_connected_positions(
+    garden: &Grid<char>,
+    explored: &mut Grid<bool>,
+    start: Point<usize>,
+) -> HashSet<Point<isize>> {
+    let target = garden[start];
+    let mut stack = vec![start];
+    let mut visited = HashSet::new();
+
+    explored[start] = true;
+
+    while let Some(location) = stack.pop() {
+        if visited.insert(location.into()) {
+            for neighbor in garden.neighbors4(location) {
+                if garden[neighbor] == target && !explored[neighbor] {
+                    explored[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    visited
+}
+
+fn calculate_perimeter(visited: &HashSet<Point<isize>>) -> usize {
+    visited
+        .iter()
+        .flat_map(|plot| plot.neighbors4())
+        .filter(|new_location| !visited.contains(new_location))
+        .count()
+}
+
+fn find_plot_with_reduced_fencing(
+    garden: &Grid<char>,
+    explored: &mut Grid<bool>,
+    position: Point<usize>,
+) -> (usize, usize) {
+    let visited = explore_connected_positions(garden, explored, position);
+
+    let mut edge_list = build_edge_list(&visited);
+
+    let perimeter = calculate_reduced_perimeter(&mut edge_list);
+
+    (visited.len(), perimeter)
+}
+
+fn build_edge_list(visited: &HashSet<Point<isize>>) -> HashSet<Edge> {
+    let mut edge_list = HashSet::new();
+
+    for &plot in visited {
+        for new_location in plot.neighbors4() {
+            if !visited.contains(&new_location) {
+                edge_list.insert((plot, new_location));
+            }
+        }
+    }
+
+    edge_list
+}
+
+fn calculate_reduced_perimeter(edge_list: &mut HashSet<Edge>) -> usize {
+    let mut perimeter = 0;
+
+    while let Some(initial_edge) = edge_list.iter().copied().next() {
+        let mut search = initial_edge;
+
+        if search.0.y == search.1.y {
+            search = find_horizontal_line_start(edge_list, search);
+        } else {
+            search = find_vertical_line_start(edge_list, search);
+        }
+
+        if search.0.y == search.1.y {
+            remove_horizontal_line(edge_list, search);
+        } else {
+            remove_vertical_line(edge_list, search);
+        }
+
+        perimeter += 1;
+    }
+
+    perimeter
+}
+
+fn find_horizontal_line_start(
+    edge_list: &HashSet<Edge>,
+    mut edge: Edge,
+) -> Edge {
+    let mut new_edge = shift(edge, Direction4::Up);
+    while edge_list.contains(&new_edge) {
+        edge = new_edge;
+        new_edge = shift(edge, Direction4::Up);
+    }
+    edge
+}
+
+fn find_vertical_line_start(
+    edge_list: &HashSet<Edge>,
+    mut edge: Edge,
+) -> Edge {
+    let mut new_edge = shift(edge, Direction4::Left);
+    while edge_list.contains(&new_edge) {
+        edge = new_edge;
+        new_edge = shift(edge, Direction4::Left);
+    }
+    edge
+}
+
+fn remove_horizontal_line(
+    edge_list: &mut HashSet<Edge>,
+    mut edge: Edge,
+) {
+    while edge_list.remove(&edge) {
+        edge = shift(edge, Direction4::Down);
+    }
+}
+
+fn remove_vertical_line(
+    edge_list: &mut HashSet<Edge>,
+    mut edge: Edge,
+) {
+    while edge_list.remove(&edge) {
+        edge = shift(edge, Direction4::Right);
+    }
+}
+
+fn shift((plot, outside): Edge, direction: Direction4) -> Edge {
+    (plot.step(direction), outside.step(direction))
+}
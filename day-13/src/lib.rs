@@ -0,0 +1,118 @@
+use crate::Part::{Part1, Part2};
+use common::error::{expect_prefix, parse_number, sections, split_once, Section};
+use common::{ParseError, Part, Point, Solution};
+
+const PART_2_SCALE: i64 = 10_000_000_000_000;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Claw {
+    button_a: Point<i64>,
+    button_b: Point<i64>,
+    prize: Point<i64>,
+}
+
+impl Claw {
+    fn with_inflated_prize(&self, offset: i64) -> Self {
+        Self {
+            prize: self.prize + Point::new(offset, offset),
+            ..*self
+        }
+    }
+
+    fn calculate_tokens(&self) -> Option<i64> {
+        let det = self.button_a.x * self.button_b.y - self.button_a.y * self.button_b.x;
+
+        if det == 0 {
+            return None;
+        }
+
+        let n = (self.button_a.x * self.prize.y - self.button_a.y * self.prize.x) / det;
+        let m = (self.prize.x - self.button_b.x * n) / self.button_a.x;
+
+        let calculated_position = self.button_a * m + self.button_b * n;
+
+        (calculated_position == self.prize).then_some(3 * m + n)
+    }
+}
+
+impl Claw {
+    fn parse(section: Section) -> Result<Self, ParseError> {
+        let mut lines = section.lines();
+        let mut next_value = |label: &str, sign: char| {
+            let (number, line) = lines
+                .next()
+                .ok_or_else(|| section.error(format!("expected a line starting with `{label}`")))?;
+            parse_value(line, label, sign).map_err(|error| error.with_line(number))
+        };
+
+        Ok(Self {
+            button_a: next_value("Button A: ", '+')?,
+            button_b: next_value("Button B: ", '+')?,
+            prize: next_value("Prize: ", '=')?,
+        })
+    }
+}
+
+fn parse_coordinate(input: &str, axis: char, sign: char) -> Result<i64, ParseError> {
+    parse_number(expect_prefix(input, &format!("{axis}{sign}"))?)
+}
+
+fn parse_value(line: &str, label: &str, sign: char) -> Result<Point<i64>, ParseError> {
+    let (x, y) = split_once(expect_prefix(line, label)?, ", ")?;
+
+    Ok(Point::new(
+        parse_coordinate(x, 'X', sign)?,
+        parse_coordinate(y, 'Y', sign)?,
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Claw>, ParseError> {
+    sections(input).into_iter().map(Claw::parse).collect()
+}
+
+fn calculate_total_tokens(claws: &[Claw], part: Part) -> i64 {
+    let scale = if part == Part2 { PART_2_SCALE } else { 0 };
+
+    claws
+        .iter()
+        .map(|claw| claw.with_inflated_prize(scale))
+        .filter_map(|claw| claw.calculate_tokens())
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Claw>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(claws: &Self::Input) -> i64 {
+        calculate_total_tokens(claws, Part1)
+    }
+
+    fn part2(claws: &Self::Input) -> i64 {
+        calculate_total_tokens(claws, Part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use common::Solution;
+
+    #[test]
+    fn reports_the_line_of_a_malformed_button() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                     Button A: Y+26, X+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n";
+
+        assert_eq!(
+            Day13::parse(input).unwrap_err().to_string(),
+            "line 5: expected `X+`, found `Y+26`"
+        );
+    }
+}
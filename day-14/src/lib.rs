@@ -0,0 +1,146 @@
+use common::error::{expect_prefix, parse_lines, parse_number, split_once};
+use common::{ParseError, Point, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Robot {
+    position: Point<i32>,
+    velocity: Point<i32>,
+}
+
+impl Robot {
+    fn advance(&mut self, width: i32, height: i32) {
+        let position = self.position + self.velocity;
+        self.position = Point::new(position.x.rem_euclid(width), position.y.rem_euclid(height));
+    }
+
+    fn is_on_grid_line(&self, mid_x: i32, mid_y: i32) -> bool {
+        self.position.x == mid_x || self.position.y == mid_y
+    }
+
+    fn get_quadrant(&self, mid_x: i32, mid_y: i32) -> Option<Quadrant> {
+        if self.is_on_grid_line(mid_x, mid_y) {
+            return None;
+        }
+
+        Some(match (self.position.x > mid_x, self.position.y > mid_y) {
+            (true, true) => Quadrant::TopRight,
+            (false, true) => Quadrant::TopLeft,
+            (false, false) => Quadrant::BottomLeft,
+            (true, false) => Quadrant::BottomRight,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Quadrant {
+    TopRight,
+    TopLeft,
+    BottomLeft,
+    BottomRight,
+}
+
+struct Grid {
+    width: i32,
+    height: i32,
+}
+
+impl Grid {
+    fn new(width: i32, height: i32) -> Self {
+        Self { width, height }
+    }
+
+    fn mid_x(&self) -> i32 {
+        self.width / 2
+    }
+    fn mid_y(&self) -> i32 {
+        self.height / 2
+    }
+}
+
+fn parse_point(s: &str) -> Result<Point<i32>, ParseError> {
+    let (x, y) = split_once(s, ",")?;
+
+    Ok(Point::new(parse_number(x)?, parse_number(y)?))
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pos, vel) = split_once(s, " ")?;
+
+        Ok(Self {
+            position: parse_point(expect_prefix(pos, "p=")?)?,
+            velocity: parse_point(expect_prefix(vel, "v=")?)?,
+        })
+    }
+}
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(robots: &Self::Input) -> i32 {
+        get_safety_factor(robots.clone())
+    }
+
+    fn part2(robots: &Self::Input) -> i32 {
+        get_fewest_seconds_to_form_picture(robots.clone())
+    }
+}
+
+fn get_safety_factor(mut robots: Vec<Robot>) -> i32 {
+    let grid = Grid::new(WIDTH, HEIGHT);
+
+    for _ in 1..=100 {
+        advance_robots(&mut robots, &grid);
+    }
+
+    calculate_safety_factor(&robots, &grid)
+}
+
+fn advance_robots(robots: &mut [Robot], grid: &Grid) {
+    robots
+        .iter_mut()
+        .for_each(|robot| robot.advance(grid.width, grid.height));
+}
+
+fn calculate_safety_factor(robots: &[Robot], grid: &Grid) -> i32 {
+    let mut quadrant_counts = [0; 4];
+
+    for robot in robots {
+        if let Some(quadrant) = robot.get_quadrant(grid.mid_x(), grid.mid_y()) {
+            quadrant_counts[quadrant as usize] += 1;
+        }
+    }
+
+    quadrant_counts.iter().product()
+}
+
+fn get_fewest_seconds_to_form_picture(mut robots: Vec<Robot>) -> i32 {
+    let grid = Grid::new(WIDTH, HEIGHT);
+
+    for seconds in 1.. {
+        advance_robots(&mut robots, &grid);
+
+        let unique_positions: HashSet<_> = robots.iter().map(|robot| robot.position).collect();
+
+        if unique_positions.len() == robots.len() {
+            return seconds;
+        }
+    }
+
+    unreachable!("Solution should be found")
+}
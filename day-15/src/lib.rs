@@ -0,0 +1,341 @@
+use std::collections::{HashSet, VecDeque};
+use common::error::split_sections;
+use common::{Direction4, Grid, ParseError, Point, Solution};
+use std::fmt::{self, Display, Formatter};
+use itertools::Itertools;
+
+pub mod play;
+pub mod replay;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Robot,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Empty,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '@' => Ok(Tile::Robot),
+            'O' => Ok(Tile::Box),
+            '[' => Ok(Tile::BoxLeft),
+            ']' => Ok(Tile::BoxRight),
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Wall),
+            _ => Err(ParseError::new(format!("expected a tile, found `{c}`"))),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(val: Tile) -> Self {
+        match val {
+            Tile::Robot => '@',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+struct Warehouse {
+    grid: Grid<Tile>,
+    /// Every swap of two tiles, in order, when someone wants to take moves back.
+    journal: Option<Vec<(Point<usize>, Point<usize>)>>,
+}
+
+impl Warehouse {
+    fn new(map: &Grid<Tile>) -> Self {
+        Self {
+            grid: map.clone(),
+            journal: None,
+        }
+    }
+
+    fn find_robot(&self) -> Option<Point<isize>> {
+        self.grid.find(&Tile::Robot).map(Point::from)
+    }
+
+    fn tile(&self, position: Point<isize>) -> Tile {
+        self.grid.get_signed(position).copied().unwrap_or(Tile::Wall)
+    }
+
+    fn swap_tiles(&mut self, first: Point<isize>, second: Point<isize>) {
+        let first = self.grid.to_position(first);
+        let second = self.grid.to_position(second);
+
+        if let (Some(first), Some(second)) = (first, second) {
+            let tile = self.grid[first];
+            self.grid[first] = self.grid[second];
+            self.grid[second] = tile;
+            if let Some(journal) = &mut self.journal {
+                journal.push((first, second));
+            }
+        }
+    }
+
+    fn attempt_move(&mut self, direction: Direction4) {
+        let robot = self.find_robot().expect("Robot not found");
+        let mut next = robot.step(direction);
+
+        match self.tile(next) {
+            Tile::Empty => self.swap_tiles(robot, next),
+            Tile::Box => {
+                let mut boxes_to_move = vec![];
+                while self.tile(next) == Tile::Box {
+                    boxes_to_move.push(next);
+                    next = next.step(direction);
+                }
+
+                if self.tile(next) == Tile::Empty {
+                    for &box_position in boxes_to_move.iter().rev() {
+                        self.swap_tiles(box_position, box_position.step(direction));
+                    }
+                    self.swap_tiles(robot, robot.step(direction));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn from_scaled(map: &Grid<Tile>) -> Self {
+        let rows = map
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|&tile| match tile {
+                        Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                        Tile::Robot => [Tile::Robot, Tile::Empty],
+                        _ => [tile, tile],
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            grid: Grid::from_rows(rows),
+            journal: None,
+        }
+    }
+
+    fn attempt_scaled_move(&mut self, direction: Direction4) {
+        if let Some(robot) = self.find_robot() {
+            let next = robot.step(direction);
+
+            match self.tile(next) {
+                Tile::Empty => self.swap_tiles(robot, next),
+                Tile::BoxLeft | Tile::BoxRight => {
+                    let mut queue = VecDeque::from([robot]);
+                    let mut seen = HashSet::new();
+                    
+                    while let Some(current) = queue.pop_front() {
+                        if seen.insert(current) {
+                            let next = current.step(direction);
+                            match self.tile(next) {
+                                Tile::Wall => return,
+                                Tile::BoxLeft => {
+                                    queue.extend([next, next.step(Direction4::Right)]);
+                                }
+                                Tile::BoxRight => {
+                                    queue.extend([next, next.step(Direction4::Left)]);
+                                }
+                                _ => continue,
+                            }
+                        }
+                    }
+
+                    let seen_sorted = match direction {
+                        Direction4::Up => seen.iter().sorted_by_key(|position| position.y),
+                        Direction4::Right => seen.iter().sorted_by_key(|position| -position.x),
+                        Direction4::Down => seen.iter().sorted_by_key(|position| -position.y),
+                        Direction4::Left => seen.iter().sorted_by_key(|position| position.x),
+                    };
+
+                    for &position in seen_sorted {
+                        self.swap_tiles(position.step(direction), position);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn calculate_gps_score(&self, scoring_tile: Tile) -> usize {
+        self.grid
+            .find_all(&scoring_tile)
+            .map(|position| 100 * position.y + position.x)
+            .sum()
+    }
+}
+
+pub struct Puzzle {
+    map: Grid<Tile>,
+    moves: Vec<Direction4>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [map_section, moves_section] = split_sections(input)?;
+
+        let map = Grid::parse_with(map_section, Tile::try_from)?;
+        if map.find_all(&Tile::Robot).count() != 1 {
+            return Err(
+                map_section.error("expected the warehouse to contain exactly one robot `@`")
+            );
+        }
+
+        let moves = moves_section
+            .lines()
+            .flat_map(|(number, line)| {
+                (1..).zip(line.chars()).map(move |(column, c)| {
+                    Direction4::try_from(c).map_err(|message| {
+                        ParseError::new(message)
+                            .with_line(number)
+                            .with_column(column)
+                    })
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Puzzle { map, moves })
+    }
+
+    fn part1(puzzle: &Self::Input) -> usize {
+        let mut warehouse = Warehouse::new(&puzzle.map);
+        for &direction in &puzzle.moves {
+            warehouse.attempt_move(direction);
+        }
+        warehouse.calculate_gps_score(Tile::Box)
+    }
+
+    fn part2(puzzle: &Self::Input) -> usize {
+        let mut warehouse = Warehouse::from_scaled(&puzzle.map);
+        for &direction in &puzzle.moves {
+            warehouse.attempt_scaled_move(direction);
+        }
+        warehouse.calculate_gps_score(Tile::BoxLeft)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::play::Session;
+    use crate::replay::Replay;
+    use crate::Day15;
+    use common::{Direction4, Solution};
+
+    const TEST: &str = include_str!("../test.txt");
+    const TEST_2: &str = include_str!("../test-2.txt");
+
+    #[test]
+    fn replays_every_move_as_a_frame() {
+        let puzzle = Day15::parse(TEST_2).unwrap();
+        let replay = Replay::record(&puzzle, false, 0, None).unwrap();
+
+        assert_eq!(replay.moves, 15);
+        assert_eq!(replay.frames.len(), 16);
+        let start = TEST_2.split("\n\n").next().unwrap();
+        assert_eq!(replay.frames[0].tiles.trim_end(), start);
+        assert_eq!(
+            replay.frames[15].tiles,
+            "########\n#....OO#\n##.....#\n#.....O#\n\
+             #.#O@..#\n#...O..#\n#...O..#\n########\n"
+        );
+        assert_eq!(replay.frames[1].marker(15), "move 1 of 15: < blocked at (2, 2)");
+        assert_eq!(replay.frames[2].marker(15), "move 2 of 15: ^ moved to (2, 1)");
+    }
+
+    #[test]
+    fn exports_a_range_of_frames() {
+        let puzzle = Day15::parse(TEST_2).unwrap();
+        let replay = Replay::record(&puzzle, true, 3, Some(5)).unwrap();
+        assert_eq!(replay.frames.len(), 3);
+
+        let text = replay.to_text();
+        assert_eq!(text.matches("--- move ").count(), 3);
+        assert!(text.starts_with("--- move 3 of 15: ^ "));
+
+        let cast = replay.to_asciicast(0.25);
+        let lines: Vec<&str> = cast.lines().collect();
+        let widest = replay.frames.iter().map(|frame| frame.marker(15).len()).max();
+        assert_eq!(
+            lines[0],
+            format!(
+                r#"{{"version": 2, "width": {}, "height": 9, "title": "day 15 replay"}}"#,
+                widest.unwrap()
+            )
+        );
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].starts_with(r#"[0.500, "o", "\u001b[2J\u001b[Hmove 5 of 15: "#));
+
+        assert!(Replay::record(&puzzle, true, 5, Some(3)).is_err());
+        assert!(Replay::record(&puzzle, true, 0, Some(16)).is_err());
+    }
+
+    #[test]
+    fn undoes_and_redoes_moves() {
+        let puzzle = Day15::parse(TEST_2).unwrap();
+        let mut session = Session::new(&puzzle, false);
+        let start = session.render();
+        assert_eq!(session.counts(), (0, 15));
+
+        while session.redo() {}
+        assert_eq!(session.gps(), 2028);
+        assert_eq!(session.move_string(), "<^^>>>vv<v>>v<<");
+
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(session.counts(), (13, 2));
+        let replay = Replay::record(&puzzle, false, 13, Some(13)).unwrap();
+        assert_eq!(session.render(), replay.frames[0].tiles);
+
+        assert!(session.step(Direction4::Up));
+        assert_eq!(session.counts(), (14, 0));
+        assert!(!session.redo());
+
+        while session.undo() {}
+        assert_eq!(session.render(), start);
+    }
+
+    #[test]
+    fn loads_a_move_string_into_a_scaled_warehouse() {
+        let puzzle = Day15::parse(TEST).unwrap();
+        let mut session = Session::new(&puzzle, true);
+
+        let moves = TEST.split("\n\n").nth(1).unwrap();
+        session.load(moves).unwrap();
+        assert_eq!(session.gps(), 9021);
+        assert_eq!(session.counts(), (700, 0));
+
+        for _ in 0..50 {
+            session.undo();
+        }
+        let replay = Replay::record(&puzzle, true, 650, Some(650)).unwrap();
+        assert_eq!(session.render(), replay.frames[0].tiles);
+        while session.redo() {}
+        assert_eq!(session.gps(), 9021);
+
+        let error = session.load("<^x").unwrap_err();
+        assert_eq!(error.to_string(), "move 3: `x` is not one of `^>v<`");
+        assert_eq!(session.gps(), 9021);
+    }
+}
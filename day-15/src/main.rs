@@ -1,295 +1,173 @@
-use std::collections::{HashSet, VecDeque};
-use crate::Part::{Part1, Part2};
+use common::Part::{Part1, Part2};
+use common::Solution;
+use common::Direction4;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+use day_15::play::Session;
+use day_15::replay::Replay;
+use day_15::Day15;
+use std::env;
+use std::error::Error;
 use std::fs;
-use itertools::Itertools;
-
-#[derive(PartialEq, Debug)]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
-        match c {
-            '^' => Direction::Up,
-            '>' => Direction::Right,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            _ => panic!("Invalid direction: {}", c),
-        }
-    }
+use std::io::{self, Write};
+
+const MODES: [&str; 2] = ["replay", "play"];
+/// Where `play` saves and loads its move string.
+const SAVE_PATH: &str = "./moves.txt";
+const KEYS: &str = "arrows move, u undo, r redo, s save, l load, q quit";
+/// Seconds between frames of an asciicast recording.
+const FRAME_DELAY: f64 = 0.1;
+
+/// The options of the `replay` and `play` modes.
+struct Options {
+    path: String,
+    scaled: bool,
+    asciicast: bool,
+    from: usize,
+    to: Option<usize>,
 }
 
-impl Direction {
-    fn to_delta(self) -> (i32, i32) {
-        match self {
-            Direction::Up => (-1, 0),
-            Direction::Right => (0, 1),
-            Direction::Down => (1, 0),
-            Direction::Left => (0, -1),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Tile {
-    Robot,
-    Box,
-    BoxLeft,
-    BoxRight,
-    Empty,
-    Wall,
-}
-
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
-        match c {
-            '@' => Tile::Robot,
-            'O' => Tile::Box,
-            '[' => Tile::BoxLeft,
-            ']' => Tile::BoxRight,
-            '.' => Tile::Empty,
-            _ => Tile::Wall,
-        }
-    }
-}
-
-impl Into<char> for Tile {
-    fn into(self) -> char {
-        match self {
-            Tile::Robot => '@',
-            Tile::Box => 'O',
-            Tile::BoxLeft => '[',
-            Tile::BoxRight => ']',
-            Tile::Empty => '.',
-            Tile::Wall => '#',
-        }
-    }
-}
-
-struct Warehouse {
-    grid: Vec<Vec<Tile>>,
-}
-
-impl Warehouse {
-    fn new(input: &str) -> Self {
-        let grid = input
-            .lines()
-            .map(|line| line.chars().map(Tile::from).collect())
-            .collect();
-        Self { grid }
-    }
-
-    fn find_robot(&self) -> Option<(i32, i32)> {
-        for (y, row) in self.grid.iter().enumerate() {
-            for (x, &tile) in row.iter().enumerate() {
-                if tile == Tile::Robot {
-                    return Some((y as i32, x as i32));
+impl Options {
+    /// Reads the options after `mode`, rejecting those that only `replay` understands when the
+    /// mode is `play`.
+    fn parse(mode: &str, args: &[String]) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self {
+            path: "./input.txt".to_string(),
+            scaled: false,
+            asciicast: false,
+            from: 0,
+            to: None,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut number = || -> Result<usize, Box<dyn Error>> {
+                let value = args.next().ok_or(format!("expected a move number after `{arg}`"))?;
+                Ok(value.parse()?)
+            };
+            match arg.as_str() {
+                "--asciicast" | "--from" | "--to" if mode != "replay" => {
+                    return Err(format!("`{arg}` only applies to `replay`").into());
                 }
-            }
-        }
-        None
-    }
-
-    fn get_tile(&self, y: i32, x: i32) -> Tile {
-        self.grid
-            .get(y as usize)
-            .and_then(|row| row.get(x as usize))
-            .copied()
-            .unwrap_or(Tile::Wall)
-    }
-
-    fn set_tile(&mut self, y: i32, x: i32, tile: Tile) {
-        if let (Some(row), Some(col)) = (self.grid.get_mut(y as usize), Some(x as usize)) {
-            if let Some(cell) = row.get_mut(col) {
-                *cell = tile;
-            }
-        }
-    }
-
-    fn swap_tiles(&mut self, y1: i32, x1: i32, y2: i32, x2: i32) {
-        let tile1 = self.get_tile(y1, x1);
-        let tile2 = self.get_tile(y2, x2);
-        self.set_tile(y1, x1, tile2);
-        self.set_tile(y2, x2, tile1);
-    }
-
-    fn attempt_move(&mut self, direction: Direction) {
-        let (y, x) = self.find_robot().expect("Robot not found");
-        let (dy, dx) = direction.to_delta();
-        let (mut y2, mut x2) = (y + dy, x + dx);
-
-        match self.get_tile(y2, x2) {
-            Tile::Empty => self.swap_tiles(y, x, y2, x2),
-            Tile::Box => {
-                let mut boxes_to_move = vec![];
-                while self.get_tile(y2, x2) == Tile::Box {
-                    boxes_to_move.push((y2, x2));
-                    y2 += dy;
-                    x2 += dx;
-                }
-
-                if self.get_tile(y2, x2) == Tile::Empty {
-                    for &(by, bx) in boxes_to_move.iter().rev() {
-                        self.swap_tiles(by, bx, by + dy, bx + dx);
-                    }
-                    self.swap_tiles(y, x, y + dy, x + dx);
+                "--scaled" => options.scaled = true,
+                "--asciicast" => options.asciicast = true,
+                "--from" => options.from = number()?,
+                "--to" => options.to = Some(number()?),
+                flag if flag.starts_with("--") => {
+                    return Err(format!("unknown flag `{flag}`").into());
                 }
+                path => options.path = path.to_string(),
             }
-            _ => {}
         }
-    }
 
-    fn from_scaled(input: &str) -> Self {
-        let grid = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .flat_map(|c| match c {
-                        '#' => vec![Tile::Wall, Tile::Wall],
-                        'O' => vec![Tile::BoxLeft, Tile::BoxRight],
-                        '.' => vec![Tile::Empty, Tile::Empty],
-                        '@' => vec![Tile::Robot, Tile::Empty],
-                        _ => vec![Tile::from(c)],
-                    })
-                    .collect()
-            })
-            .collect();
-        Self { grid }
+        Ok(options)
     }
+}
 
-    fn attempt_scaled_move(&mut self, direction: Direction) {
-        if let Some((y, x)) = self.find_robot() {
-            let (dy, dx) = direction.to_delta();
-            let (y2, x2) = (y + dy, x + dx);
-
-            match self.get_tile(y2, x2) {
-                Tile::Empty => self.swap_tiles(y, x, y2, x2),
-                Tile::BoxLeft | Tile::BoxRight => {
-                    let mut queue = VecDeque::from([(y, x)]);
-                    let mut seen = HashSet::new();
-                    
-                    while let Some((cy, cx)) = queue.pop_front() {
-                        if seen.insert((cy, cx)) {
-                            let ny = cy + dy;
-                            let nx = cx + dx;
-                            match self.get_tile(ny, nx) {
-                                Tile::Wall => return,
-                                Tile::BoxLeft => {
-                                    queue.extend([(ny, nx), (ny, nx + 1)]);
-                                }
-                                Tile::BoxRight => {
-                                    queue.extend([(ny, nx), (ny, nx - 1)]);
-                                }
-                                _ => continue,
-                            }
-                        }
-                    }
+fn draw(session: &Session, message: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    execute!(
+        stdout,
+        terminal::Clear(terminal::ClearType::All),
+        cursor::MoveTo(0, 0)
+    )?;
+
+    let (moves, undone) = session.counts();
+    let mut screen = session.render();
+    screen += &format!("moves {moves}, {undone} to redo, GPS {}\n{KEYS}\n", session.gps());
+    screen += message;
+    write!(stdout, "{}", screen.replace('\n', "\r\n"))?;
+    stdout.flush()
+}
 
-                    let seen_sorted = match (dy, dx) {
-                        (-1, 0) => seen.iter().sorted_by_key(|&&(y, _)| y),
-                        (0, 1) => seen.iter().sorted_by_key(|&&(_, x)| -x),
-                        (1, 0) => seen.iter().sorted_by_key(|&&(y, _)| -y),
-                        _ => seen.iter().sorted_by_key(|&&(_, x)| x),
-                    };
+fn play_keys(session: &mut Session) -> io::Result<()> {
+    let mut message = String::new();
 
-                    for &(sy, sx) in seen_sorted {
-                        self.swap_tiles(sy + dy, sx + dx, sy, sx);
-                    }
-                }
-                _ => {}
-            }
+    loop {
+        draw(session, &message)?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
         }
-    }
 
-    fn calculate_gps_score(&self, scoring_tile: Tile) -> usize {
-        self.grid
-            .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter().enumerate().filter_map(move |(x, &tile)| {
-                    if tile == scoring_tile {
-                        Some(100 * y + x)
-                    } else {
-                        None
-                    }
-                })
-            })
-            .sum()
+        message = match key.code {
+            KeyCode::Up => step(session, Direction4::Up),
+            KeyCode::Right => step(session, Direction4::Right),
+            KeyCode::Down => step(session, Direction4::Down),
+            KeyCode::Left => step(session, Direction4::Left),
+            KeyCode::Char('u') if !session.undo() => "nothing to undo".to_string(),
+            KeyCode::Char('r') if !session.redo() => "nothing to redo".to_string(),
+            KeyCode::Char('s') => match fs::write(SAVE_PATH, session.move_string() + "\n") {
+                Ok(()) => format!("saved to {SAVE_PATH}"),
+                Err(error) => format!("cannot save to {SAVE_PATH}: {error}"),
+            },
+            KeyCode::Char('l') => match fs::read_to_string(SAVE_PATH) {
+                Ok(moves) => match session.load(&moves) {
+                    Ok(()) => format!("loaded {SAVE_PATH}"),
+                    Err(error) => format!("cannot load {SAVE_PATH}: {error}"),
+                },
+                Err(error) => format!("cannot load {SAVE_PATH}: {error}"),
+            },
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            _ => String::new(),
+        };
     }
 }
 
-fn get_value(file_path: &str, part: Part) -> usize {
-    let file_contents = fs::read_to_string(file_path)
-        .expect("Should have been able to read the file");
-    
-    let (map_str, moves_str) = file_contents
-        .split_once("\n\n")
-        .expect("Invalid input format");
-
-    let moves = moves_str
-        .chars()
-        .filter(|c| *c != '\n')
-        .map(Direction::from);
-
-    match part {
-        Part1 => {
-            let mut warehouse = Warehouse::new(map_str);
-            for direction in moves {
-                warehouse.attempt_move(direction);
-            }
-            warehouse.calculate_gps_score(Tile::Box)
-        }
-        Part2 => {
-            let mut warehouse = Warehouse::from_scaled(map_str);
-            for direction in moves {
-                warehouse.attempt_scaled_move(direction);
-            }
-            warehouse.calculate_gps_score(Tile::BoxLeft)
-        }
+fn step(session: &mut Session, direction: Direction4) -> String {
+    if session.step(direction) {
+        String::new()
+    } else {
+        format!("{} is blocked", direction.to_char())
     }
 }
 
-fn main() {
-    println!("Part 1 value: {}", get_value("./input.txt", Part1));
-    println!("Part 2 value: {}", get_value("./input.txt", Part2));
-}
+/// Drives the session from the keyboard on the alternate screen, putting the terminal back
+/// however the session ends.
+fn play(mut session: Session) -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
 
-#[cfg(test)]
-mod tests {
-    use crate::get_value;
-    use crate::Part::{Part1, Part2};
+    let result = play_keys(&mut session);
 
-    #[test]
-    fn returns_expected_value_test_data_for_part_1() {
-        let value = get_value("./test.txt", Part1);
-        assert_eq!(value, 10092);
+    execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+/// With no arguments both parts are solved. `replay` instead prints the warehouse after every
+/// move, each frame headed by a marker line, or writes an asciicast v2 recording with
+/// `--asciicast`. `--scaled` replays the wide warehouse of part 2, `--from` and `--to` limit the
+/// frames to a range of moves, and the input is read from the path given after the mode. `play`
+/// drives the robot with the arrow keys, in the wide warehouse with `--scaled`, and can undo and
+/// redo moves and save or load them in `moves.txt`.
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Some(mode) = args.first().filter(|mode| !MODES.contains(&mode.as_str())) {
+        return Err(format!("unknown mode `{mode}`; expected one of {}", MODES.join(", ")).into());
     }
 
-    #[test]
-    fn returns_expected_value_for_input_data_for_part_1() {
-        let value = get_value("./input.txt", Part1);
-        assert_eq!(value, 1465523);
+    if args.is_empty() {
+        let input = fs::read_to_string("./input.txt")?;
+        println!("Part 1 value: {}", Day15::solve(&input, Part1)?);
+        println!("Part 2 value: {}", Day15::solve(&input, Part2)?);
+        return Ok(());
     }
 
-    #[test]
-    fn returns_expected_value_test_data_for_part_2() {
-        let value = get_value("./test.txt", Part2);
-        assert_eq!(value, 9021);
+    let options = Options::parse(&args[0], &args[1..])?;
+    let puzzle = Day15::parse(&fs::read_to_string(&options.path)?)?;
+    if args[0] == "play" {
+        return Ok(play(Session::new(&puzzle, options.scaled))?);
     }
 
-    #[test]
-    fn returns_expected_value_for_input_data_for_part_2() {
-        let value = get_value("./input.txt", Part2);
-        assert_eq!(value, 1471049);
+    let replay = Replay::record(&puzzle, options.scaled, options.from, options.to)?;
+    if options.asciicast {
+        print!("{}", replay.to_asciicast(FRAME_DELAY));
+    } else {
+        print!("{}", replay.to_text());
     }
+
+    Ok(())
 }
@@ -0,0 +1,130 @@
+use crate::{Puzzle, Tile, Warehouse};
+use common::{Direction4, Grid, ParseError, Point};
+use std::mem;
+
+/// A move and the swaps of tiles it made, which undo it when made again in reverse.
+type MadeMove = (Direction4, Vec<(Point<usize>, Point<usize>)>);
+
+/// A warehouse being driven by hand, with every move kept so that it can be undone, redone and
+/// saved as a move string in the puzzle's format.
+pub struct Session {
+    map: Grid<Tile>,
+    scaled: bool,
+    warehouse: Warehouse,
+    moves: Vec<MadeMove>,
+    /// Moves that can be redone, the next one last.
+    undone: Vec<Direction4>,
+}
+
+fn warehouse(map: &Grid<Tile>, scaled: bool) -> Warehouse {
+    let mut warehouse = if scaled {
+        Warehouse::from_scaled(map)
+    } else {
+        Warehouse::new(map)
+    };
+    warehouse.journal = Some(Vec::new());
+    warehouse
+}
+
+impl Session {
+    /// Starts from the puzzle's layout, in the doubled-width warehouse of part 2 when `scaled` is
+    /// set, with the puzzle's own moves waiting to be redone one at a time.
+    pub fn new(puzzle: &Puzzle, scaled: bool) -> Self {
+        Self {
+            map: puzzle.map.clone(),
+            scaled,
+            warehouse: warehouse(&puzzle.map, scaled),
+            moves: Vec::new(),
+            undone: puzzle.moves.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Makes a move with the push rules of the warehouse's width and remembers how to undo it.
+    fn apply(&mut self, direction: Direction4) {
+        if self.scaled {
+            self.warehouse.attempt_scaled_move(direction);
+        } else {
+            self.warehouse.attempt_move(direction);
+        }
+        let swaps = self.warehouse.journal.as_mut().map(mem::take);
+        self.moves.push((direction, swaps.unwrap_or_default()));
+    }
+
+    /// Moves the robot, pushing any boxes in the way, and forgets the moves that could have been
+    /// redone. Returns whether the robot got anywhere.
+    pub fn step(&mut self, direction: Direction4) -> bool {
+        self.apply(direction);
+        self.undone.clear();
+        !self.moves[self.moves.len() - 1].1.is_empty()
+    }
+
+    /// Takes back the last move by swapping its tiles back. Returns whether there was a move to
+    /// take back.
+    pub fn undo(&mut self) -> bool {
+        let Some((direction, swaps)) = self.moves.pop() else {
+            return false;
+        };
+
+        let grid = &mut self.warehouse.grid;
+        for &(first, second) in swaps.iter().rev() {
+            let tile = grid[first];
+            grid[first] = grid[second];
+            grid[second] = tile;
+        }
+        self.undone.push(direction);
+        true
+    }
+
+    /// Makes the last move that was undone again. Returns whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(direction) = self.undone.pop() else {
+            return false;
+        };
+        self.apply(direction);
+        true
+    }
+
+    /// The moves made so far, as `^`, `>`, `v` and `<`.
+    pub fn move_string(&self) -> String {
+        self.moves
+            .iter()
+            .map(|(direction, _)| direction.to_char())
+            .collect()
+    }
+
+    /// Starts over and makes the moves in `moves`, ignoring whitespace. The session is left as it
+    /// was if any of them is not a move.
+    pub fn load(&mut self, moves: &str) -> Result<(), ParseError> {
+        let moves = (1..)
+            .zip(moves.chars().filter(|c| !c.is_whitespace()))
+            .map(|(index, c)| {
+                Direction4::try_from(c)
+                    .map_err(|message| ParseError::new(format!("move {index}: {message}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.warehouse = warehouse(&self.map, self.scaled);
+        self.moves.clear();
+        for direction in moves {
+            self.apply(direction);
+        }
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// How many moves have been made and how many can be redone.
+    pub fn counts(&self) -> (usize, usize) {
+        (self.moves.len(), self.undone.len())
+    }
+
+    /// The sum of the GPS coordinates of every box.
+    pub fn gps(&self) -> usize {
+        let scoring_tile = if self.scaled { Tile::BoxLeft } else { Tile::Box };
+        self.warehouse.calculate_gps_score(scoring_tile)
+    }
+
+    /// The warehouse as it stands, one line per row.
+    pub fn render(&self) -> String {
+        self.warehouse.grid.to_string()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Puzzle, Warehouse};
+use common::{Direction4, Point};
+use std::fmt::Write;
+
+/// The warehouse as it stood after some number of moves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// How many moves have been made; frame 0 is the starting layout.
+    pub step: usize,
+    /// The move that led here, if any.
+    pub direction: Option<Direction4>,
+    /// Whether the robot got anywhere on that move.
+    pub moved: bool,
+    pub robot: Point<isize>,
+    /// The tiles, one line per row.
+    pub tiles: String,
+}
+
+impl Frame {
+    fn capture(
+        warehouse: &Warehouse,
+        step: usize,
+        direction: Option<Direction4>,
+        moved: bool,
+    ) -> Self {
+        Self {
+            step,
+            direction,
+            moved,
+            robot: warehouse.find_robot().expect("Robot not found"),
+            tiles: warehouse.grid.to_string(),
+        }
+    }
+
+    /// A one-line description of the frame, such as `move 12 of 700: < blocked at (4, 3)`.
+    pub fn marker(&self, moves: usize) -> String {
+        match self.direction {
+            None => format!("start of {moves} moves: robot at {}", self.robot),
+            Some(direction) => format!(
+                "move {} of {moves}: {} {} {}",
+                self.step,
+                direction.to_char(),
+                if self.moved { "moved to" } else { "blocked at" },
+                self.robot
+            ),
+        }
+    }
+}
+
+/// The frames of the robot working through a range of its moves.
+pub struct Replay {
+    pub frames: Vec<Frame>,
+    /// How many moves the whole run has, including those outside the range.
+    pub moves: usize,
+}
+
+impl Replay {
+    /// Records the warehouse after move `from` through to after move `to`, both inclusive, in the
+    /// doubled-width warehouse of part 2 when `scaled` is set. Move 0 is the starting layout and
+    /// `to` defaults to the last move; moves after `to` are never made.
+    pub fn record(
+        puzzle: &Puzzle,
+        scaled: bool,
+        from: usize,
+        to: Option<usize>,
+    ) -> Result<Self, String> {
+        let moves = puzzle.moves.len();
+        let to = to.unwrap_or(moves);
+        if from > to {
+            return Err(format!("the range starts at move {from} but ends at move {to}"));
+        }
+        if to > moves {
+            return Err(format!("there are only {moves} moves, not {to}"));
+        }
+
+        let mut warehouse = if scaled {
+            Warehouse::from_scaled(&puzzle.map)
+        } else {
+            Warehouse::new(&puzzle.map)
+        };
+        let mut frames = Vec::with_capacity(to - from + 1);
+        if from == 0 {
+            frames.push(Frame::capture(&warehouse, 0, None, false));
+        }
+
+        let attempt = |warehouse: &mut Warehouse, direction| {
+            if scaled {
+                warehouse.attempt_scaled_move(direction);
+            } else {
+                warehouse.attempt_move(direction);
+            }
+        };
+
+        for (index, &direction) in puzzle.moves[..to].iter().enumerate() {
+            let step = index + 1;
+            if step < from {
+                attempt(&mut warehouse, direction);
+                continue;
+            }
+
+            let before = warehouse.find_robot();
+            attempt(&mut warehouse, direction);
+            let moved = warehouse.find_robot() != before;
+            frames.push(Frame::capture(&warehouse, step, Some(direction), moved));
+        }
+
+        Ok(Self { frames, moves })
+    }
+
+    /// The frames as plain text, each headed by a `--- marker ---` line.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for frame in &self.frames {
+            let _ = writeln!(text, "--- {} ---", frame.marker(self.moves));
+            text += &frame.tiles;
+        }
+        text
+    }
+
+    /// The frames as an asciicast v2 recording, shown `delay` seconds apart with the marker on
+    /// the top line. The terminal is sized to fit the widest line and the tallest frame.
+    pub fn to_asciicast(&self, delay: f64) -> String {
+        let screens: Vec<String> = self
+            .frames
+            .iter()
+            .map(|frame| format!("{}\n{}", frame.marker(self.moves), frame.tiles))
+            .collect();
+        let lines = || screens.iter().flat_map(|screen| screen.lines());
+        let width = lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let height = screens.iter().map(|screen| screen.lines().count()).max().unwrap_or(0);
+
+        let mut cast = format!(
+            "{{\"version\": 2, \"width\": {width}, \"height\": {height}, \
+             \"title\": \"day 15 replay\"}}\n"
+        );
+        for (index, screen) in screens.iter().enumerate() {
+            let screen = format!("\u{1b}[2J\u{1b}[H{}", screen.replace('\n', "\r\n"));
+            let _ = writeln!(
+                cast,
+                "[{:.3}, \"o\", {}]",
+                index as f64 * delay,
+                serde_json::to_string(&screen).expect("a string always serializes")
+            );
+        }
+
+        cast
+    }
+}
@@ -0,0 +1,48 @@
+use common::ParseError;
+use std::collections::HashMap;
+
+/// What each move through the maze costs. The default is the reindeer's: 1 per step, 1000 per
+/// quarter turn, and no way to turn around other than turning twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MazeCosts {
+    pub step: u32,
+    pub left_turn: u32,
+    pub right_turn: u32,
+    /// Turning around on the spot in one move, or `None` to allow only quarter turns.
+    pub u_turn: Option<u32>,
+    /// The cost of stepping onto a tile with this character, in place of `step`.
+    pub terrain: HashMap<char, u32>,
+}
+
+impl Default for MazeCosts {
+    fn default() -> Self {
+        Self {
+            step: 1,
+            left_turn: 1000,
+            right_turn: 1000,
+            u_turn: None,
+            terrain: HashMap::new(),
+        }
+    }
+}
+
+impl MazeCosts {
+    /// The cost of stepping onto a tile showing `tile`.
+    pub fn step_onto(&self, tile: char) -> u32 {
+        self.terrain.get(&tile).copied().unwrap_or(self.step)
+    }
+
+    /// Every route has to turn to come back to a state it has been in, so turns that cost
+    /// something keep loops from being free, which enumerating best routes relies on.
+    pub(crate) fn validate(&self) -> Result<(), ParseError> {
+        let turns = [Some(self.left_turn), Some(self.right_turn), self.u_turn];
+        if turns.contains(&Some(0)) {
+            return Err(ParseError::new("turns must cost at least 1"));
+        }
+        if self.terrain.contains_key(&'#') {
+            return Err(ParseError::new("`#` is a wall and cannot have a terrain cost"));
+        }
+
+        Ok(())
+    }
+}
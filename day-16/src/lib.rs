@@ -0,0 +1,264 @@
+pub mod costs;
+mod ranking;
+pub mod route;
+
+use common::search::{bfs, dijkstra, Paths};
+use common::{Direction4, Grid, ParseError, Point, Solution};
+use costs::MazeCosts;
+use route::{BestRoutes, Route};
+use std::collections::HashSet;
+
+type State = (Point<usize>, Direction4);
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: Point<usize>,
+    end: Point<usize>,
+    facing: Direction4,
+    costs: MazeCosts,
+}
+
+impl Maze {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input)?;
+        let find = |c: char| {
+            grid.find(&c)
+                .ok_or_else(|| ParseError::new(format!("expected the maze to contain `{c}`")))
+        };
+
+        let (start, end) = (find('S')?, find('E')?);
+
+        // Turning on the spot is always allowed, so `E` can be reached facing any way as long as
+        // some run of open tiles leads to it, whatever the costs and starting direction.
+        let reachable = bfs([start], |&position| {
+            grid.neighbors4(position)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        });
+        if reachable.cost(&end).is_none() {
+            return Err(ParseError::new("no route leads from `S` to `E`"));
+        }
+
+        Ok(Self {
+            start,
+            end,
+            grid,
+            facing: Direction4::Right,
+            costs: MazeCosts::default(),
+        })
+    }
+
+    /// Prices moves with `costs` instead of the reindeer's.
+    pub fn with_costs(mut self, costs: MazeCosts) -> Result<Self, ParseError> {
+        costs.validate()?;
+        self.costs = costs;
+        Ok(self)
+    }
+
+    /// Starts facing `direction` instead of East.
+    pub fn facing(mut self, direction: Direction4) -> Self {
+        self.facing = direction;
+        self
+    }
+
+    /// One cheapest route from `S` to `E`.
+    pub fn best_route(&self) -> Route {
+        let (paths, end) = self.explore();
+        let best = Self::best_score(&paths, end);
+        let end_state = Self::end_states(end)
+            .find(|state| paths.cost(state) == Some(best))
+            .expect("The best score is reached at some end state");
+
+        Route {
+            score: best,
+            states: paths.path_to(&end_state).unwrap_or_default(),
+        }
+    }
+
+    /// Every cheapest route from `S` to `E`, and the tiles that any of them cross.
+    pub fn best_routes(&self) -> BestRoutes {
+        let (paths, end) = self.explore();
+        let best = Self::best_score(&paths, end);
+        let best_end_states: Vec<State> = Self::end_states(end)
+            .filter(|state| paths.cost(state) == Some(best))
+            .collect();
+
+        BestRoutes::new(paths, best, best_end_states)
+    }
+
+    /// Draws the maze with `route` as `^>v<` arrows, each pointing the way the reindeer leaves
+    /// that tile, and every other tile in `tiles` as `O`.
+    pub fn render(&self, route: &Route, tiles: &HashSet<Point<usize>>) -> String {
+        let mut grid = self.grid.map(|&c| match c {
+            '#' | 'S' | 'E' => c,
+            _ => '.',
+        });
+
+        for &tile in tiles {
+            if grid[tile] == '.' {
+                grid[tile] = 'O';
+            }
+        }
+        for &(position, direction) in &route.states {
+            if !matches!(grid[position], 'S' | 'E') {
+                grid[position] = direction.to_char();
+            }
+        }
+
+        grid.to_string()
+    }
+
+    fn explore(&self) -> (Paths<State, u32>, Point<usize>) {
+        let paths = dijkstra([(self.start, self.facing)], |&state| self.moves(state));
+
+        (paths, self.end)
+    }
+
+    fn moves(&self, (position, direction): State) -> Vec<(State, u32)> {
+        let mut moves = vec![
+            ((position, direction.turn_right()), self.costs.right_turn),
+            ((position, direction.turn_left()), self.costs.left_turn),
+        ];
+        if let Some(cost) = self.costs.u_turn {
+            moves.push(((position, direction.reverse()), cost));
+        }
+
+        if let Some(next) = self.grid.step(position, direction) {
+            if self.grid[next] != '#' {
+                moves.push(((next, direction), self.costs.step_onto(self.grid[next])));
+            }
+        }
+
+        moves
+    }
+
+    fn best_score(paths: &Paths<State, u32>, end: Point<usize>) -> u32 {
+        Self::end_states(end)
+            .filter_map(|state| paths.cost(&state))
+            .min()
+            .expect("Maze::new checks that the end is reachable")
+    }
+
+    fn end_states(end: Point<usize>) -> impl Iterator<Item = State> {
+        Direction4::ALL.into_iter().map(move |direction| (end, direction))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::new(input)
+    }
+
+    fn part1(maze: &Self::Input) -> u32 {
+        maze.best_route().score
+    }
+
+    fn part2(maze: &Self::Input) -> usize {
+        maze.best_routes().tiles.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::costs::MazeCosts;
+    use crate::Maze;
+    use common::Direction4;
+    use std::collections::{HashMap, HashSet};
+
+    const TEST: &str = include_str!("../test.txt");
+
+    #[test]
+    fn lists_every_best_route_as_moves() {
+        let maze = Maze::new(TEST).unwrap();
+        let routes = maze.best_routes().routes();
+        let mut moves: Vec<String> = routes.iter().map(|route| route.moves()).collect();
+        moves.sort_unstable();
+
+        assert_eq!(
+            moves,
+            [
+                "L F10 R F2 R F10 L F2 L F2 R F6 L F2 R F2 L F2 R F2 L F8",
+                "L F10 R F2 R F10 L F2 L F4 R F2 L F2 R F4 L F2 R F4 L F6",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_a_best_route_with_arrows() {
+        let maze = Maze::new(TEST).unwrap();
+        let route = maze.best_route();
+        let rendered = maze.render(&route, &HashSet::new());
+
+        assert_eq!(route.score, 11048);
+        assert_eq!(rendered.trim_end(), TEST.trim_end());
+    }
+
+    #[test]
+    fn rejects_a_maze_whose_end_is_walled_off() {
+        let error = Maze::new("######\n#S.#E#\n######\n").err().unwrap();
+
+        assert_eq!(error.to_string(), "no route leads from `S` to `E`");
+    }
+
+    #[test]
+    fn respects_the_starting_orientation() {
+        let maze = Maze::new(TEST).unwrap().facing(Direction4::Up);
+
+        assert_eq!(maze.best_route().score, 10048);
+        assert_eq!(maze.best_routes().tiles.len(), 64);
+    }
+
+    #[test]
+    fn prices_turns_and_terrain_with_maze_costs() {
+        let corridor = "#####\n#S~E#\n#####\n";
+        let maze = || Maze::new(corridor).unwrap().facing(Direction4::Left);
+        assert_eq!(maze().best_route().score, 2002);
+
+        let costs = MazeCosts {
+            step: 2,
+            right_turn: 7,
+            u_turn: Some(5),
+            terrain: HashMap::from([('~', 10)]),
+            ..MazeCosts::default()
+        };
+        let route = maze().with_costs(costs).unwrap().best_route();
+        assert_eq!(route.score, 5 + 10 + 2);
+        assert_eq!(route.moves(), "U F2");
+
+        let free_turns = MazeCosts {
+            left_turn: 0,
+            ..MazeCosts::default()
+        };
+        let error = maze().with_costs(free_turns).err().unwrap();
+        assert_eq!(error.to_string(), "turns must cost at least 1");
+    }
+
+    #[test]
+    fn ranks_the_k_best_distinct_routes() {
+        let maze = Maze::new(TEST).unwrap();
+        let routes = maze.k_best_routes(5);
+        let scores: Vec<u32> = routes.iter().map(|route| route.score).collect();
+
+        assert_eq!(scores, [11048, 11048, 11056, 11056, 12048]);
+        assert!(routes[2].tiles().len() > routes[0].tiles().len());
+        assert!(routes
+            .iter()
+            .enumerate()
+            .all(|(index, route)| !routes[..index].contains(route)));
+    }
+
+    #[test]
+    fn lists_the_routes_within_a_slack_of_the_best() {
+        let maze = Maze::new(TEST).unwrap();
+
+        assert_eq!(maze.routes_within(0, 100).len(), 2);
+        assert_eq!(maze.routes_within(8, 100).len(), 4);
+        assert_eq!(maze.routes_within(8, 3).len(), 3);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::route::Route;
+use crate::{Maze, State};
+use common::search::{dijkstra, Paths};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+impl Maze {
+    /// The moves that lead into `state` and what they cost, the reverse of [`Maze::moves`].
+    fn moves_into(&self, (position, direction): State) -> Vec<(State, u32)> {
+        let mut moves = vec![
+            ((position, direction.turn_left()), self.costs.right_turn),
+            ((position, direction.turn_right()), self.costs.left_turn),
+        ];
+        if let Some(cost) = self.costs.u_turn {
+            moves.push(((position, direction.reverse()), cost));
+        }
+
+        if let Some(previous) = self.grid.step(position, direction.reverse()) {
+            if self.grid[previous] != '#' && self.grid[position] != '#' {
+                let cost = self.costs.step_onto(self.grid[position]);
+                moves.push(((previous, direction), cost));
+            }
+        }
+
+        moves
+    }
+
+    /// The cheapest cost from every state that can reach `E`.
+    fn distances_to_end(&self) -> Paths<State, u32> {
+        dijkstra(Self::end_states(self.end), |&state| self.moves_into(state))
+    }
+
+    /// The cost of each prefix of `states`, starting with 0 for the first state alone.
+    fn prefix_costs(&self, states: &[State]) -> Vec<u32> {
+        let mut costs = vec![0];
+        for pair in states.windows(2) {
+            let step = self
+                .moves(pair[0])
+                .into_iter()
+                .find(|&(next, _)| next == pair[1])
+                .map_or(0, |(_, cost)| cost);
+            costs.push(costs[costs.len() - 1] + step);
+        }
+        costs
+    }
+
+    /// The cheapest way from `from` to `E`, having already `spent` some cost, that avoids the
+    /// `banned` states and moves and costs no more than `bound` in total.
+    ///
+    /// This is A* guided by the exact distances to `E`; banning only ever makes the way
+    /// longer, so they never overestimate. Among equally promising states the one further along
+    /// goes first, which makes the search run straight down a best path when nothing is in the
+    /// way, and the bound keeps it from wandering far when something is.
+    fn spur(
+        &self,
+        (from, spent): (State, u32),
+        bound: u32,
+        to_end: &Paths<State, u32>,
+        banned_states: &HashSet<State>,
+        banned_moves: &HashSet<(State, State)>,
+    ) -> Option<Route> {
+        let estimate = |cost: u32, state: &State| {
+            to_end.cost(state).map(|rest| cost.saturating_add(rest))
+        };
+
+        let mut costs = HashMap::from([(from, spent)]);
+        let mut previous = HashMap::new();
+        let mut heap = BinaryHeap::from([(Reverse(estimate(spent, &from)?), spent, from)]);
+
+        while let Some((_, cost, state)) = heap.pop() {
+            if costs[&state] < cost {
+                continue;
+            }
+            if state.0 == self.end {
+                let mut states = vec![state];
+                while let Some(&before) = previous.get(states.last().unwrap()) {
+                    states.push(before);
+                }
+                states.reverse();
+                return Some(Route { score: cost, states });
+            }
+
+            for (next, step) in self.moves(state) {
+                let next_cost = cost.saturating_add(step);
+                let Some(total) = estimate(next_cost, &next) else {
+                    continue;
+                };
+                if total > bound
+                    || banned_states.contains(&next)
+                    || banned_moves.contains(&(state, next))
+                    || costs.get(&next).is_some_and(|&known| known <= next_cost)
+                {
+                    continue;
+                }
+
+                costs.insert(next, next_cost);
+                previous.insert(next, state);
+                heap.push((Reverse(total), next_cost, next));
+            }
+        }
+
+        None
+    }
+
+    /// Routes from cheapest up, found with Yen's algorithm: each next route is the cheapest
+    /// one that leaves an earlier route at some state and never repeats a state. Stops at
+    /// `limit` routes, or at the first one costing more than `slack` over the best.
+    fn ranked_routes(&self, limit: usize, slack: Option<u32>) -> Vec<Route> {
+        let to_end = self.distances_to_end();
+        let start = ((self.start, self.facing), 0);
+        let none = (HashSet::new(), HashSet::new());
+
+        let Some(first) = self.spur(start, u32::MAX, &to_end, &none.0, &none.1) else {
+            return Vec::new();
+        };
+        let worst = slack.map_or(u32::MAX, |slack| first.score.saturating_add(slack));
+        if limit == 0 {
+            return Vec::new();
+        }
+
+        let mut routes = vec![first];
+        let mut candidates: Vec<Route> = Vec::new();
+        while routes.len() < limit {
+            let previous = routes[routes.len() - 1].states.clone();
+            let spent = self.prefix_costs(&previous);
+
+            for i in 0..previous.len() - 1 {
+                // Only the cheapest candidates still needed can make the list, so a spur that
+                // cannot beat them is not worth finding.
+                candidates.sort_unstable_by_key(|route| route.score);
+                let needed = limit - routes.len();
+                let bound = match candidates.get(needed - 1) {
+                    Some(route) => worst.min(route.score),
+                    None => worst,
+                };
+
+                let root = &previous[..=i];
+                let banned_moves = routes
+                    .iter()
+                    .filter(|route| route.states.len() > i + 1 && route.states[..=i] == *root)
+                    .map(|route| (route.states[i], route.states[i + 1]))
+                    .collect();
+                let banned_states = root[..i].iter().copied().collect();
+                let spur = (previous[i], spent[i]);
+
+                if let Some(tail) = self.spur(spur, bound, &to_end, &banned_states, &banned_moves)
+                {
+                    let route = Route {
+                        score: tail.score,
+                        states: [&root[..i], &tail.states[..]].concat(),
+                    };
+                    if !candidates.contains(&route) && !routes.contains(&route) {
+                        candidates.push(route);
+                    }
+                }
+            }
+
+            let cheapest = (0..candidates.len())
+                .min_by_key(|&index| (candidates[index].score, candidates[index].states.len()));
+            match cheapest.map(|index| candidates.swap_remove(index)) {
+                Some(route) if route.score <= worst => routes.push(route),
+                _ => break,
+            }
+        }
+
+        routes
+    }
+
+    /// The `k` cheapest distinct routes from `S` to `E`, cheapest first.
+    pub fn k_best_routes(&self, k: usize) -> Vec<Route> {
+        self.ranked_routes(k, None)
+    }
+
+    /// Every route that costs at most `slack` more than the best one, cheapest first, up to
+    /// `limit` of them.
+    pub fn routes_within(&self, slack: u32, limit: usize) -> Vec<Route> {
+        self.ranked_routes(limit, Some(slack))
+    }
+}
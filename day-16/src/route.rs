@@ -0,0 +1,90 @@
+use crate::State;
+use common::search::Paths;
+use common::Point;
+use std::collections::HashSet;
+
+/// One way through the maze, as every state the reindeer passes through from `S` to `E`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub score: u32,
+    pub states: Vec<State>,
+}
+
+impl Route {
+    /// Every tile the route crosses, including both ends.
+    pub fn tiles(&self) -> HashSet<Point<usize>> {
+        self.states.iter().map(|&(position, _)| position).collect()
+    }
+
+    /// The route as a compact list of moves: `F3` steps forward three tiles, `R` and `L` turn
+    /// right and left, and `U` turns around.
+    pub fn moves(&self) -> String {
+        let mut moves = Vec::new();
+        let mut forward = 0;
+
+        for pair in self.states.windows(2) {
+            let [(from, facing), (to, turned)] = [pair[0], pair[1]];
+            if from != to {
+                forward += 1;
+                continue;
+            }
+
+            if forward > 0 {
+                moves.push(format!("F{forward}"));
+                forward = 0;
+            }
+            let turn = if turned == facing.turn_right() {
+                "R"
+            } else if turned == facing.turn_left() {
+                "L"
+            } else {
+                "U"
+            };
+            moves.push(turn.to_string());
+        }
+        if forward > 0 {
+            moves.push(format!("F{forward}"));
+        }
+
+        moves.join(" ")
+    }
+}
+
+/// The optimal routes through the maze. Counting their tiles does not need the routes
+/// themselves, which can be far more numerous, so they are only listed on request.
+pub struct BestRoutes {
+    pub score: u32,
+    /// The tiles on any of the routes.
+    pub tiles: HashSet<Point<usize>>,
+    paths: Paths<State, u32>,
+    ends: Vec<State>,
+}
+
+impl BestRoutes {
+    pub(crate) fn new(paths: Paths<State, u32>, score: u32, ends: Vec<State>) -> Self {
+        let tiles = paths
+            .nodes_on_paths_to(ends.iter().copied())
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect();
+
+        Self {
+            score,
+            tiles,
+            paths,
+            ends,
+        }
+    }
+
+    /// Every optimal route, one per distinct sequence of states.
+    pub fn routes(&self) -> Vec<Route> {
+        self.ends
+            .iter()
+            .flat_map(|end| self.paths.all_paths_to(end))
+            .map(|states| Route {
+                score: self.score,
+                states,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::instruction::{Instruction, Opcode};
+use crate::Computer;
+use common::error::{parse_number, Section};
+use common::ParseError;
+use std::collections::HashMap;
+
+/// One instruction line of a source file, before labels are resolved.
+struct Statement<'a> {
+    line: usize,
+    opcode: Opcode,
+    operand: Option<&'a str>,
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn three_bits(text: &str) -> Result<u8, ParseError> {
+    match parse_number::<u8>(text)? {
+        value @ 0..=7 => Ok(value),
+        value => Err(ParseError::new(format!(
+            "operand {value} does not fit in 3 bits"
+        ))),
+    }
+}
+
+fn combo(text: &str) -> Result<u8, ParseError> {
+    match text.to_ascii_uppercase().as_str() {
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        _ => three_bits(text),
+    }
+}
+
+impl Statement<'_> {
+    fn encode(&self, labels: &HashMap<&str, usize>) -> Result<Instruction, ParseError> {
+        let mnemonic = self.opcode.mnemonic();
+        let operand = match (self.opcode, self.operand) {
+            (Opcode::Bxc, None) => Ok(0),
+            (_, None) => Err(ParseError::new(format!("`{mnemonic}` needs an operand"))),
+            (opcode, Some(operand)) if opcode.takes_combo() => combo(operand),
+            (Opcode::Jnz, Some(label)) if is_label(label) => match labels.get(label) {
+                Some(&address) if address <= 7 => Ok(address as u8),
+                Some(address) => Err(ParseError::new(format!(
+                    "label `{label}` is at {address}, beyond the 3-bit jump range"
+                ))),
+                None => Err(ParseError::new(format!("undefined label `{label}`"))),
+            },
+            (_, Some(operand)) => three_bits(operand),
+        };
+
+        Ok(Instruction {
+            opcode: self.opcode,
+            operand: operand.map_err(|error| error.with_line(self.line))?,
+        })
+    }
+}
+
+/// Assembles a program written as mnemonics into a [`Computer`].
+///
+/// Each line holds an optional `label:`, then an instruction such as `bst A` or `jnz loop`, and
+/// anything after `;` is a comment. Combo operands may name a register; `jnz` may name a label.
+/// `.a`, `.b` and `.c` set the initial registers, which otherwise start at 0. A numeric label such
+/// as `06:` is checked against the address it is on, so a disassembly listing assembles back to
+/// the same program.
+pub fn assemble(source: &str) -> Result<Computer, ParseError> {
+    let mut computer = Computer::default();
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+
+    for (line, text) in Section::new(source).lines() {
+        let mut code = text.split(';').next().unwrap_or_default().trim();
+
+        while let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            let address = statements.len() * 2;
+
+            if let Ok(expected) = label.parse::<usize>() {
+                if expected != address {
+                    return Err(ParseError::new(format!(
+                        "address {label} does not match the instruction's address {address:02}"
+                    ))
+                    .with_line(line));
+                }
+            } else if !is_label(label) {
+                return Err(ParseError::new(format!("`{label}` is not a valid label"))
+                    .with_line(line));
+            } else if labels.insert(label, address).is_some() {
+                return Err(ParseError::new(format!("label `{label}` is defined twice"))
+                    .with_line(line));
+            }
+            code = rest.trim();
+        }
+
+        let mut words = code.split_whitespace();
+        let Some(first) = words.next() else {
+            continue;
+        };
+        let operand = words.next();
+        if let Some(extra) = words.next() {
+            return Err(ParseError::new(format!("unexpected `{extra}`")).with_line(line));
+        }
+
+        if let Some(register) = first.strip_prefix('.') {
+            let value = operand
+                .ok_or_else(|| ParseError::new(format!("`{first}` needs a value")))
+                .and_then(parse_number)
+                .map_err(|error| error.with_line(line))?;
+            match register.to_ascii_lowercase().as_str() {
+                "a" => computer.a = value,
+                "b" => computer.b = value,
+                "c" => computer.c = value,
+                _ => {
+                    return Err(
+                        ParseError::new(format!("unknown directive `{first}`")).with_line(line)
+                    )
+                }
+            }
+            continue;
+        }
+
+        let opcode = Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic().eq_ignore_ascii_case(first))
+            .ok_or_else(|| {
+                ParseError::new(format!("unknown instruction `{first}`")).with_line(line)
+            })?;
+        statements.push(Statement {
+            line,
+            opcode,
+            operand,
+        });
+    }
+
+    for statement in &statements {
+        let instruction = statement.encode(&labels)?;
+        computer.program.push(instruction.opcode as u8);
+        computer.program.push(instruction.operand);
+    }
+
+    Ok(computer)
+}
+
+impl Computer {
+    /// The program as assembly source, with directives for its registers, which [`assemble`]
+    /// turns back into the same computer.
+    pub fn to_source(&self) -> String {
+        let mut source = format!(".a {}\n.b {}\n.c {}\n\n", self.a, self.b, self.c);
+        for line in self.disassemble() {
+            source += &line;
+            source.push('\n');
+        }
+
+        source
+    }
+
+    /// The program in the puzzle's `Register A: ...` / `Program: ...` input format.
+    pub fn to_input(&self) -> String {
+        let program: Vec<String> = self.program.iter().map(ToString::to_string).collect();
+
+        format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+            self.a,
+            self.b,
+            self.c,
+            program.join(",")
+        )
+    }
+}
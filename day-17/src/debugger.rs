@@ -0,0 +1,183 @@
+use crate::error::VmError;
+use crate::{Computer, STEP_BUDGET};
+use std::collections::BTreeSet;
+
+const HELP: &str = "\
+commands:
+  s, step [n]       execute the next n instructions (default 1)
+  c, continue       run until a breakpoint or the program halts
+  b, break <addr>   toggle a breakpoint at an instruction address
+  r, regs           show the registers and instruction pointer
+  set <a|b|c> <n>   change a register
+  l, list           disassemble the program; `>` marks the next instruction, `*` a breakpoint
+  o, output         show everything output so far
+  reset             restart from the initial registers
+  q, quit           leave the debugger";
+
+/// An interactive debugger over a [`Computer`], driven one command line at a time.
+pub struct Debugger {
+    initial: Computer,
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    output: Vec<i64>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            initial: computer.clone(),
+            computer,
+            breakpoints: BTreeSet::new(),
+            output: Vec::new(),
+        }
+    }
+
+    fn registers(&self) -> String {
+        let [a, b, c] = self.computer.registers();
+        format!(
+            "A={a} B={b} C={c} ip={:02}",
+            self.computer.instruction_pointer()
+        )
+    }
+
+    fn step(&mut self) -> Result<String, String> {
+        let (line, output) = self
+            .computer
+            .trace_step()
+            .map_err(|error| format!("error: {error}"))?;
+        self.output.extend(output);
+        Ok(line)
+    }
+
+    fn halted(&self) -> String {
+        format!("halted; output {}", crate::format_result(self.output.clone()))
+    }
+
+    fn step_many(&mut self, count: usize) -> String {
+        let mut lines = Vec::new();
+
+        for _ in 0..count {
+            if self.computer.is_halted() {
+                lines.push(self.halted());
+                break;
+            }
+            match self.step() {
+                Ok(line) => lines.push(line),
+                Err(error) => {
+                    lines.push(error);
+                    break;
+                }
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Runs until the next breakpoint, giving up after `STEP_BUDGET` instructions so that a
+    /// program that never halts hands control back.
+    fn resume(&mut self) -> String {
+        for steps in 0..STEP_BUDGET {
+            if self.computer.is_halted() {
+                return self.halted();
+            }
+            let address = self.computer.instruction_pointer();
+            if steps > 0 && self.breakpoints.contains(&address) {
+                return format!("breakpoint at {address:02}; {}", self.registers());
+            }
+            if let Err(error) = self.step() {
+                return error;
+            }
+        }
+
+        format!(
+            "error: {}; {}",
+            VmError::StepBudgetExceeded {
+                budget: STEP_BUDGET
+            },
+            self.registers()
+        )
+    }
+
+    fn toggle_breakpoint(&mut self, address: usize) -> String {
+        if address % 2 == 1 || address >= self.computer.program.len() {
+            return format!("{address} is not an instruction address");
+        }
+
+        if self.breakpoints.remove(&address) {
+            format!("removed breakpoint at {address:02}")
+        } else {
+            self.breakpoints.insert(address);
+            format!("breakpoint at {address:02}")
+        }
+    }
+
+    fn set_register(&mut self, register: &str, value: i64) -> String {
+        match register.to_ascii_lowercase().as_str() {
+            "a" => self.computer.a = value,
+            "b" => self.computer.b = value,
+            "c" => self.computer.c = value,
+            _ => return format!("unknown register `{register}`"),
+        }
+
+        self.registers()
+    }
+
+    fn list(&self) -> String {
+        self.computer
+            .disassemble()
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let address = index * 2;
+                let next = if address == self.computer.instruction_pointer() {
+                    '>'
+                } else {
+                    ' '
+                };
+                let breakpoint = if self.breakpoints.contains(&address) {
+                    '*'
+                } else {
+                    ' '
+                };
+                format!("{next}{breakpoint} {line}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Runs one command, returning the text to show or `None` when the user asked to quit.
+    pub fn execute(&mut self, command: &str) -> Option<String> {
+        let words: Vec<&str> = command.split_whitespace().collect();
+
+        let reply = match words.as_slice() {
+            [] => String::new(),
+            ["q" | "quit"] => return None,
+            ["h" | "help"] => HELP.to_string(),
+            ["s" | "step"] => self.step_many(1),
+            ["s" | "step", count] => match count.parse() {
+                Ok(count) => self.step_many(count),
+                Err(_) => format!("`{count}` is not a number of steps"),
+            },
+            ["c" | "continue"] => self.resume(),
+            ["b" | "break", address] => match address.parse() {
+                Ok(address) => self.toggle_breakpoint(address),
+                Err(_) => format!("`{address}` is not an address"),
+            },
+            ["r" | "regs"] => self.registers(),
+            ["set", register, value] => match value.parse() {
+                Ok(value) => self.set_register(register, value),
+                Err(_) => format!("`{value}` is not a number"),
+            },
+            ["l" | "list"] => self.list(),
+            ["o" | "output"] => crate::format_result(self.output.clone()),
+            ["reset"] => {
+                self.computer = self.initial.clone();
+                self.output.clear();
+                self.registers()
+            }
+            _ => format!("unknown command `{command}`; try `help`"),
+        };
+
+        Some(reply)
+    }
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why the computer stopped before halting. Every error is raised before the failing
+/// instruction changes any state, so the computer can still be inspected afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmError {
+    InvalidOpcode { address: usize, opcode: u8 },
+    InvalidOperand { address: usize, operand: u8 },
+    ReservedOperand { address: usize },
+    MissingOperand { address: usize },
+    /// A combo operand too large (or negative) to divide A by `2^shift`.
+    ShiftOverflow { address: usize, shift: i64 },
+    /// A jump to an odd address or past the end of the program.
+    InvalidJump { address: usize, target: usize },
+    StepBudgetExceeded { budget: usize },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VmError::InvalidOpcode { address, opcode } => {
+                write!(f, "invalid opcode {opcode} at {address:02}")
+            }
+            VmError::InvalidOperand { address, operand } => {
+                write!(f, "operand {operand} at {address:02} does not fit in 3 bits")
+            }
+            VmError::ReservedOperand { address } => {
+                write!(f, "reserved combo operand 7 at {address:02}")
+            }
+            VmError::MissingOperand { address } => {
+                write!(f, "the opcode at {address:02} has no operand")
+            }
+            VmError::ShiftOverflow { address, shift } => {
+                write!(f, "shifting by {shift} at {address:02} overflows")
+            }
+            VmError::InvalidJump { address, target } => write!(
+                f,
+                "the jump at {address:02} targets {target}, which is not an instruction"
+            ),
+            VmError::StepBudgetExceeded { budget } => {
+                write!(f, "the program did not halt within {budget} steps")
+            }
+        }
+    }
+}
+
+impl Error for VmError {}
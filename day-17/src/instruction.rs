@@ -0,0 +1,100 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    pub fn from_u8(value: u8) -> Option<Opcode> {
+        Self::ALL.get(value as usize).copied()
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// `bxl` and `jnz` read their operand as a literal and `bxc` ignores it; the rest read a
+    /// combo operand.
+    pub fn takes_combo(self) -> bool {
+        !matches!(self, Opcode::Bxl | Opcode::Jnz | Opcode::Bxc)
+    }
+}
+
+/// One decoded opcode and operand pair. It displays as it is written in assembly: combo operands
+/// name their register, and the operand `bxc` ignores is only shown when it is not 0 so that the
+/// listing assembles back to the same program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Option<Instruction> {
+        Some(Instruction {
+            opcode: Opcode::from_u8(opcode)?,
+            operand,
+        })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = self.opcode.mnemonic();
+
+        match (self.opcode, self.operand) {
+            (Opcode::Bxc, 0) => write!(f, "{mnemonic}"),
+            (opcode, 4) if opcode.takes_combo() => write!(f, "{mnemonic} A"),
+            (opcode, 5) if opcode.takes_combo() => write!(f, "{mnemonic} B"),
+            (opcode, 6) if opcode.takes_combo() => write!(f, "{mnemonic} C"),
+            (_, operand) => write!(f, "{mnemonic} {operand}"),
+        }
+    }
+}
+
+/// Renders `program` as one `address: mnemonic operand` line per instruction.
+pub fn disassemble(program: &[u8]) -> Vec<String> {
+    program
+        .chunks(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            let address = index * 2;
+            match pair {
+                [opcode, operand] => match Instruction::decode(*opcode, *operand) {
+                    Some(instruction) => format!("{address:02}: {instruction}"),
+                    None => format!("{address:02}: ??? {opcode},{operand}"),
+                },
+                [value] => format!("{address:02}: ??? {value}"),
+                _ => unreachable!("chunks are never empty"),
+            }
+        })
+        .collect()
+}
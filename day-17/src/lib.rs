@@ -0,0 +1,362 @@
+pub mod assembler;
+pub mod debugger;
+pub mod error;
+pub mod instruction;
+pub mod quine;
+
+use common::error::{expect_prefix, parse_number, Section};
+use common::{ParseError, Solution};
+use error::VmError;
+use instruction::{Instruction, Opcode};
+use quine::QuineError;
+
+/// How many instructions a program may execute before it is assumed never to halt.
+pub const STEP_BUDGET: usize = 10_000_000;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Computer {
+    a: i64,
+    b: i64,
+    c: i64,
+    program: Vec<u8>,
+    instruction_pointer: usize,
+}
+
+impl Computer {
+    /// The text following `label` on the `index`th non-blank line, with its line number.
+    fn labelled_line<'a>(
+        lines: &[(usize, &'a str)],
+        index: usize,
+        label: &str,
+    ) -> Result<(usize, &'a str), ParseError> {
+        let &(number, line) = lines
+            .get(index)
+            .ok_or_else(|| ParseError::new(format!("expected a line starting with `{label}`")))?;
+        let value = expect_prefix(line, label).map_err(|error| error.with_line(number))?;
+
+        Ok((number, value))
+    }
+
+    fn parse_register(
+        lines: &[(usize, &str)],
+        index: usize,
+        label: &str,
+    ) -> Result<i64, ParseError> {
+        let (number, value) = Self::labelled_line(lines, index, label)?;
+        parse_number(value).map_err(|error| error.with_line(number))
+    }
+
+    fn from_input(input: &str) -> Result<Computer, ParseError> {
+        let lines: Vec<_> = Section::new(input)
+            .lines()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .collect();
+        let (number, values) = Self::labelled_line(&lines, 3, "Program: ")?;
+
+        Ok(Self {
+            a: Self::parse_register(&lines, 0, "Register A: ")?,
+            b: Self::parse_register(&lines, 1, "Register B: ")?,
+            c: Self::parse_register(&lines, 2, "Register C: ")?,
+            program: values
+                .split(',')
+                .map(parse_number)
+                .collect::<Result<_, _>>()
+                .map_err(|error| error.with_line(number))?,
+            instruction_pointer: 0,
+        })
+    }
+
+    pub fn registers(&self) -> [i64; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.instruction_pointer >= self.program.len()
+    }
+
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        let opcode = *self.program.get(self.instruction_pointer)?;
+        let operand = *self.program.get(self.instruction_pointer + 1)?;
+        Instruction::decode(opcode, operand)
+    }
+
+    pub fn disassemble(&self) -> Vec<String> {
+        instruction::disassemble(&self.program)
+    }
+
+    /// Divides A by `2^shift`, which `adv`, `bdv` and `cdv` all share.
+    fn divide(&self, address: usize, shift: i64) -> Result<i64, VmError> {
+        match u32::try_from(shift) {
+            Ok(shift) if shift < i64::BITS - 1 => Ok(self.a / (1 << shift)),
+            _ => Err(VmError::ShiftOverflow { address, shift }),
+        }
+    }
+
+    /// Executes the instruction at the instruction pointer, returning the value it outputs.
+    fn step(&mut self) -> Result<Option<i64>, VmError> {
+        let address = self.instruction_pointer;
+        let opcode = self.program[address];
+        let operand = *self
+            .program
+            .get(address + 1)
+            .ok_or(VmError::MissingOperand { address })?;
+        let Instruction { opcode, operand } = Instruction::decode(opcode, operand)
+            .ok_or(VmError::InvalidOpcode { address, opcode })?;
+        if operand > 7 {
+            return Err(VmError::InvalidOperand { address, operand });
+        }
+
+        let literal = operand as i64;
+        let combo = match literal {
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            7 if opcode.takes_combo() => return Err(VmError::ReservedOperand { address }),
+            _ => literal,
+        };
+
+        let mut next = address + 2;
+        let mut output = None;
+        match opcode {
+            Opcode::Adv => self.a = self.divide(address, combo)?,
+            Opcode::Bxl => self.b ^= literal,
+            Opcode::Bst => self.b = combo % 8,
+            Opcode::Jnz if self.a != 0 => {
+                let target = literal as usize;
+                if target % 2 == 1 || target > self.program.len() {
+                    return Err(VmError::InvalidJump { address, target });
+                }
+                next = target;
+            }
+            Opcode::Jnz => {}
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => output = Some(combo % 8),
+            Opcode::Bdv => self.b = self.divide(address, combo)?,
+            Opcode::Cdv => self.c = self.divide(address, combo)?,
+        }
+        self.instruction_pointer = next;
+
+        Ok(output)
+    }
+
+    /// Executes one instruction and describes it along with the registers it left behind.
+    fn trace_step(&mut self) -> Result<(String, Option<i64>), VmError> {
+        let address = self.instruction_pointer;
+        let instruction = self
+            .current_instruction()
+            .map_or_else(|| String::from("???"), |instruction| instruction.to_string());
+        let output = self.step()?;
+
+        let mut line = format!(
+            "{address:02}: {instruction:<6}  A={} B={} C={}",
+            self.a, self.b, self.c
+        );
+        if let Some(output) = output {
+            line += &format!("  out {output}");
+        }
+
+        Ok((line, output))
+    }
+
+    /// Runs the program until it halts, returning one line per executed instruction. A final
+    /// `error:` line explains why it stopped early if it did not halt within `budget` steps or
+    /// could not execute an instruction.
+    pub fn trace(&mut self, budget: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        while !self.is_halted() {
+            if lines.len() == budget {
+                lines.push(format!("error: {}", VmError::StepBudgetExceeded { budget }));
+                break;
+            }
+            match self.trace_step() {
+                Ok((line, _)) => lines.push(line),
+                Err(error) => {
+                    lines.push(format!("error: {error}"));
+                    break;
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Runs the program until it halts, failing if that takes more than `budget` instructions.
+    pub fn run_with_budget(&mut self, budget: usize) -> Result<Vec<i64>, VmError> {
+        let mut result = Vec::new();
+
+        for _ in 0..budget {
+            if self.is_halted() {
+                return Ok(result);
+            }
+            if let Some(output) = self.step()? {
+                result.push(output);
+            }
+        }
+
+        if self.is_halted() {
+            Ok(result)
+        } else {
+            Err(VmError::StepBudgetExceeded { budget })
+        }
+    }
+
+    fn run(&mut self) -> Result<Vec<i64>, VmError> {
+        self.run_with_budget(STEP_BUDGET)
+    }
+}
+
+fn format_result(result: Vec<i64>) -> String {
+    let formatted_result = result
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    formatted_result
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = Result<String, VmError>;
+    type Answer2 = Result<i64, QuineError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Computer::from_input(input)
+    }
+
+    fn part1(computer: &Self::Input) -> Result<String, VmError> {
+        computer.clone().run().map(format_result)
+    }
+
+    fn part2(computer: &Self::Input) -> Result<i64, QuineError> {
+        computer.find_quine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assembler::assemble;
+    use crate::debugger::Debugger;
+    use crate::error::VmError;
+    use crate::Day17;
+    use common::Part::{Part1, Part2};
+    use common::Solution;
+
+    const TEST: &str = include_str!("../test.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const TEST_2: &str = include_str!("../test-2.txt");
+
+    #[test]
+    fn disassembles_the_program_with_named_combo_operands() {
+        let computer = Day17::parse(INPUT).unwrap();
+        assert_eq!(
+            computer.disassemble(),
+            [
+                "00: bst A", "02: bxl 1", "04: cdv B", "06: adv 3", "08: bxl 4", "10: bxc 5",
+                "12: out B", "14: jnz 0"
+            ]
+        );
+    }
+
+    #[test]
+    fn traces_every_executed_instruction() {
+        let trace = Day17::parse(TEST_2).unwrap().trace(100);
+        assert_eq!(trace[0], "00: adv 3   A=253 B=0 C=0");
+        assert_eq!(trace[1], "02: out A   A=253 B=0 C=0  out 5");
+        assert_eq!(trace.last().unwrap(), "04: jnz 0   A=0 B=0 C=0");
+    }
+
+    #[test]
+    fn stops_the_debugger_at_breakpoints() {
+        let mut debugger = Debugger::new(Day17::parse(TEST_2).unwrap());
+        assert_eq!(debugger.execute("break 2").unwrap(), "breakpoint at 02");
+        assert_eq!(debugger.execute("c").unwrap(), "breakpoint at 02; A=253 B=0 C=0 ip=02");
+        assert_eq!(debugger.execute("step").unwrap(), "02: out A   A=253 B=0 C=0  out 5");
+        assert_eq!(debugger.execute("set a 0").unwrap(), "A=0 B=0 C=0 ip=04");
+        assert_eq!(debugger.execute("b 2").unwrap(), "removed breakpoint at 02");
+        assert_eq!(debugger.execute("c").unwrap(), "halted; output 5");
+        assert_eq!(debugger.execute("quit"), None);
+    }
+
+    #[test]
+    fn finds_quines_for_loops_shaped_unlike_the_input() {
+        let program = "2,4,1,2,7,5,4,5,1,3,5,5,0,3,3,0";
+        let input = |a: &str| {
+            format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\n\
+                     Program: {program}")
+        };
+
+        let a = Day17::solve(&input("1"), Part2).unwrap();
+        assert_eq!(a, "37221270076916");
+        assert_eq!(Day17::solve(&input(&a), Part1).unwrap(), program);
+    }
+
+    #[test]
+    fn explains_why_a_program_has_no_quine() {
+        let error = Day17::solve(TEST, Part2).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "part 2: unsupported program: \
+             A must be divided by 8 (`adv 3`) once per loop, found `adv 1`"
+        );
+    }
+
+    #[test]
+    fn assembles_the_disassembly_back_into_the_same_program() {
+        let computer = Day17::parse(INPUT).unwrap();
+
+        let assembled = assemble(&computer.to_source()).unwrap();
+        assert_eq!(assembled, computer);
+        assert_eq!(Day17::parse(&assembled.to_input()).unwrap(), computer);
+    }
+
+    #[test]
+    fn assembles_labels_and_comments() {
+        let source = "\
+            .a 2024        ; counts down three bits at a time
+            loop:  adv 3
+                   out A   ; prints the low bits left in A
+                   jnz loop
+        ";
+        let computer = assemble(source).unwrap();
+        assert_eq!(
+            Day17::solve(&computer.to_input(), Part1).unwrap(),
+            "5,7,3,0"
+        );
+
+        let error = assemble("adv 3\njnz start\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: undefined label `start`");
+    }
+
+    #[test]
+    fn stops_runaway_programs_and_invalid_instructions_with_an_error() {
+        let run = |a: i64, program: &str| {
+            let input = format!(
+                "Register A: {a}\nRegister B: 0\nRegister C: 0\nProgram: {program}"
+            );
+            Day17::parse(&input).unwrap().run_with_budget(100)
+        };
+
+        assert_eq!(run(1, "3,0"), Err(VmError::StepBudgetExceeded { budget: 100 }));
+        assert_eq!(run(1, "5,4,8,0"), Err(VmError::InvalidOpcode { address: 2, opcode: 8 }));
+        assert_eq!(run(1, "1,9"), Err(VmError::InvalidOperand { address: 0, operand: 9 }));
+        assert_eq!(run(1, "0,7"), Err(VmError::ReservedOperand { address: 0 }));
+        assert_eq!(run(1, "5,4,5"), Err(VmError::MissingOperand { address: 2 }));
+        assert_eq!(run(64, "0,4"), Err(VmError::ShiftOverflow { address: 0, shift: 64 }));
+        assert_eq!(run(1, "3,3"), Err(VmError::InvalidJump { address: 0, target: 3 }));
+        assert_eq!(run(0, "3,3,5,4"), Ok(vec![0]));
+
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\nProgram: 0,7";
+        assert_eq!(
+            Day17::solve(input, Part1).unwrap_err().to_string(),
+            "part 1: reserved combo operand 7 at 00"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::error::VmError;
+use crate::instruction::{Instruction, Opcode};
+use crate::Computer;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuineError {
+    /// The program is not a single loop that consumes three bits of A per output.
+    Unsupported(String),
+    NoSolution,
+    Failed(VmError),
+}
+
+impl fmt::Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuineError::Unsupported(reason) => write!(f, "unsupported program: {reason}"),
+            QuineError::NoSolution => {
+                write!(f, "no initial value of A makes the program output itself")
+            }
+            QuineError::Failed(error) => write!(f, "the program failed: {error}"),
+        }
+    }
+}
+
+fn unsupported(reason: impl Into<String>) -> QuineError {
+    QuineError::Unsupported(reason.into())
+}
+
+/// Registers an instruction reads before it writes, as indexes into `[A, B, C]`.
+fn reads(instruction: Instruction) -> Vec<usize> {
+    let mut registers = match instruction.opcode {
+        Opcode::Bxl => vec![1],
+        Opcode::Bxc => vec![1, 2],
+        _ => vec![],
+    };
+    if instruction.opcode.takes_combo() && (4..=6).contains(&instruction.operand) {
+        registers.push(instruction.operand as usize - 4);
+    }
+
+    registers
+}
+
+fn writes(instruction: Instruction) -> Option<usize> {
+    match instruction.opcode {
+        Opcode::Adv => Some(0),
+        Opcode::Bxl | Opcode::Bst | Opcode::Bxc | Opcode::Bdv => Some(1),
+        Opcode::Cdv => Some(2),
+        Opcode::Jnz | Opcode::Out => None,
+    }
+}
+
+/// Checks that the program is one loop ending in `jnz 0` that outputs once, divides A by 8 once,
+/// and derives B and C from A afresh every time round. Each output then depends only on the
+/// bits of A that are still left, which is what lets the search work backwards.
+fn check_structure(program: &[u8]) -> Result<(), QuineError> {
+    if program.is_empty() || program.len() % 2 == 1 {
+        return Err(unsupported("the program must be a whole number of instructions"));
+    }
+
+    let mut instructions = Vec::new();
+    for (index, pair) in program.chunks(2).enumerate() {
+        let instruction = Instruction::decode(pair[0], pair[1]).ok_or_else(|| {
+            unsupported(format!("invalid opcode {} at {:02}", pair[0], index * 2))
+        })?;
+        if instruction.opcode.takes_combo() && instruction.operand == 7 {
+            return Err(unsupported(format!(
+                "reserved combo operand 7 at {:02}",
+                index * 2
+            )));
+        }
+        instructions.push(instruction);
+    }
+
+    let (last, body) = instructions.split_last().expect("the program is not empty");
+    if (last.opcode, last.operand) != (Opcode::Jnz, 0)
+        || body.iter().any(|instruction| instruction.opcode == Opcode::Jnz)
+    {
+        return Err(unsupported(
+            "the only jump must be a final `jnz 0` looping back to the start",
+        ));
+    }
+
+    let divisions: Vec<&Instruction> = body
+        .iter()
+        .filter(|instruction| instruction.opcode == Opcode::Adv)
+        .collect();
+    if let [division] = divisions.as_slice() {
+        if division.operand != 3 {
+            return Err(unsupported(format!(
+                "A must be divided by 8 (`adv 3`) once per loop, found `{division}`"
+            )));
+        }
+    } else {
+        return Err(unsupported(format!(
+            "A must be divided by 8 (`adv 3`) exactly once per loop, found {} divisions",
+            divisions.len()
+        )));
+    }
+
+    let outputs = body
+        .iter()
+        .filter(|instruction| instruction.opcode == Opcode::Out)
+        .count();
+    if outputs != 1 {
+        return Err(unsupported(format!(
+            "the loop must output exactly once, found {outputs} outputs"
+        )));
+    }
+
+    let mut written = [true, false, false];
+    for &instruction in body {
+        if let Some(register) = reads(instruction).into_iter().find(|&r| !written[r]) {
+            return Err(unsupported(format!(
+                "{} is read before it is written, so it carries state between loops",
+                ["A", "B", "C"][register]
+            )));
+        }
+        if let Some(register) = writes(instruction) {
+            written[register] = true;
+        }
+    }
+
+    Ok(())
+}
+
+impl Computer {
+    /// Runs one pass of the loop starting from `a`, returning the value it outputs, if any,
+    /// before it stops or fails.
+    fn first_output(&self, a: i64) -> Option<i64> {
+        let mut computer = self.clone();
+        computer.a = a;
+        computer.instruction_pointer = 0;
+
+        while !computer.is_halted() {
+            if let Some(output) = computer.step().ok()? {
+                return Some(output);
+            }
+        }
+
+        None
+    }
+
+    /// Chooses A three bits at a time from its most significant end, so that the last output
+    /// is matched first. Trying digits in ascending order finds the smallest A first.
+    fn search(&self, a: i64, remaining: usize) -> Option<i64> {
+        let Some(index) = remaining.checked_sub(1) else {
+            return Some(a);
+        };
+
+        (0..8).find_map(|digit| {
+            let candidate = a.checked_mul(8)? + digit;
+            let matches = candidate != 0
+                && self.first_output(candidate) == Some(self.program[index] as i64);
+            matches.then(|| self.search(candidate, index)).flatten()
+        })
+    }
+
+    /// Finds the smallest positive initial value of A for which the program outputs itself.
+    pub fn find_quine(&self) -> Result<i64, QuineError> {
+        check_structure(&self.program)?;
+
+        let a = self
+            .search(0, self.program.len())
+            .ok_or(QuineError::NoSolution)?;
+        let mut computer = self.clone();
+        computer.a = a;
+        let expected: Vec<i64> = self.program.iter().map(|&value| value as i64).collect();
+
+        match computer.run() {
+            Ok(output) if output == expected => Ok(a),
+            Ok(_) => Err(QuineError::NoSolution),
+            Err(error) => Err(QuineError::Failed(error)),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use common::search::astar;
+use common::error::{parse_lines, parse_number, split_once};
+use common::{Grid, ParseError, Point, Solution};
+
+#[derive(Debug)]
+pub struct MemorySpace {
+    size: usize,
+    obstacles: Vec<Point<usize>>,
+}
+
+impl MemorySpace {
+    const START: Point<usize> = Point::new(0, 0);
+    const END: Point<usize> = Point::new(70, 70);
+    const SIZE: usize = 71;
+
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let obstacles = parse_lines(input, |line| {
+            let (x, y) = split_once(line, ",")?;
+            let position = Point::new(parse_number(x)?, parse_number(y)?);
+            if position.x >= Self::SIZE || position.y >= Self::SIZE {
+                return Err(ParseError::new(format!(
+                    "byte `{line}` falls outside the {0}x{0} memory space",
+                    Self::SIZE
+                )));
+            }
+            Ok(position)
+        })?;
+        if obstacles.is_empty() {
+            return Err(ParseError::new("expected falling bytes, found empty input"));
+        }
+
+        Ok(Self {
+            size: Self::SIZE,
+            obstacles,
+        })
+    }
+
+    fn search(&self, limit: usize) -> Option<(Vec<Point<usize>>, usize)> {
+        let mut memory = Grid::filled(self.size, self.size, false);
+        for &obstacle in self.obstacles.iter().take(limit) {
+            memory.set(obstacle, true);
+        }
+
+        astar(
+            Self::START,
+            |&state| {
+                memory
+                    .neighbors4(state)
+                    .filter(|&pos| !memory[pos])
+                    .map(|pos| (pos, 1))
+                    .collect::<Vec<_>>()
+            },
+            |state| state.manhattan_distance(Self::END),
+            |state| *state == Self::END,
+        )
+    }
+
+    /// The first byte that cuts the exit off, or `None` if the exit stays reachable after every
+    /// byte has fallen.
+    fn find_critical_coordinate(&self) -> Option<Point<usize>> {
+        let mut min = 0;
+        let mut max = self.obstacles.len();
+        if self.search(max).is_some() {
+            return None;
+        }
+
+        while max > min {
+            let mid = (max + min) / 2;
+            if self.search(mid).is_none() {
+                max = mid;
+            } else {
+                min = mid + 1;
+            }
+        }
+        Some(self.obstacles[max - 1])
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = MemorySpace;
+    type Answer1 = Result<usize, &'static str>;
+    type Answer2 = Result<String, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        MemorySpace::new(input)
+    }
+
+    fn part1(memory_space: &Self::Input) -> Result<usize, &'static str> {
+        match memory_space.search(1024) {
+            Some((_, cost)) => Ok(cost),
+            None => Err("the first 1024 bytes cut the exit off"),
+        }
+    }
+
+    fn part2(memory_space: &Self::Input) -> Result<String, &'static str> {
+        let Point { x, y } = memory_space
+            .find_critical_coordinate()
+            .ok_or("no byte cuts the exit off")?;
+        Ok(format!("{},{}", x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day18;
+    use common::Part::{Part1, Part2};
+    use common::Solution;
+
+    #[test]
+    fn rejects_empty_input_and_bytes_outside_the_memory_space() {
+        let error = Day18::parse("").err().unwrap();
+        assert_eq!(error.to_string(), "expected falling bytes, found empty input");
+
+        let error = Day18::parse("1,2\n71,0\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2: byte `71,0` falls outside the 71x71 memory space");
+    }
+
+    #[test]
+    fn reports_an_exit_that_is_cut_off_or_never_is() {
+        let wall: String = (0..71).map(|x| format!("{x},1\n")).collect();
+        let error = Day18::solve(&wall, Part1).unwrap_err();
+        assert_eq!(error.to_string(), "part 1: the first 1024 bytes cut the exit off");
+        assert_eq!(Day18::solve(&wall, Part2).unwrap(), "70,1");
+
+        let error = Day18::solve("5,5\n", Part2).unwrap_err();
+        assert_eq!(error.to_string(), "part 2: no byte cuts the exit off");
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Part::{Part1, Part2};
+use common::error::split_sections;
+use common::{ParseError, Part, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+struct Cache {
+    memo: HashMap<String, usize>,
+}
+
+impl Cache {
+    fn count_valid_designs(&mut self, design: &str, patterns: &[String]) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+        
+        if let Some(&count) = self.memo.get(design) {
+            return count;
+        }
+
+        let count = patterns
+            .iter()
+            .filter(|pattern| design.starts_with(*pattern))
+            .map(|pattern| self.count_valid_designs(&design[pattern.len()..], patterns))
+            .sum();
+            
+        self.memo.insert(design.to_string(), count);
+        count
+    }
+}
+
+pub struct DesignParser {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl DesignParser {
+    fn new(contents: &str) -> Result<Self, ParseError> {
+        let [patterns, designs] = split_sections(contents)?;
+
+        let patterns = patterns
+            .text()
+            .trim_end()
+            .split(", ")
+            .map(String::from)
+            .collect();
+
+        let designs = designs
+            .lines()
+            .map(|(_, design)| String::from(design))
+            .collect();
+
+        Ok(Self { patterns, designs })
+    }
+    
+    fn count_designs(&self, part: Part) -> usize {
+        let mut cache = Cache::default();
+        match part {
+            Part1 => self.designs
+                .iter()
+                .filter(|design| cache.count_valid_designs(design, &self.patterns) > 0)
+                .count(),
+            Part2 => self.designs
+                .iter()
+                .map(|design| cache.count_valid_designs(design, &self.patterns))
+                .sum()
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = DesignParser;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        DesignParser::new(input)
+    }
+
+    fn part1(parser: &Self::Input) -> usize {
+        parser.count_designs(Part1)
+    }
+
+    fn part2(parser: &Self::Input) -> usize {
+        parser.count_designs(Part2)
+    }
+}
@@ -0,0 +1,74 @@
+use common::search::bfs;
+use common::{Grid, ParseError, Point, Solution};
+
+#[derive(Debug)]
+pub struct Race {
+    start: Point<usize>,
+    end: Point<usize>,
+    maze: Grid<char>,
+    path: Vec<Point<usize>>,
+}
+
+impl Race {
+    fn find_path(&mut self) {
+        let paths = bfs([self.start], |&position| {
+            self.maze
+                .neighbors4(position)
+                .filter(|&next| self.maze[next] != '#')
+                .collect::<Vec<_>>()
+        });
+
+        self.path = paths.path_to(&self.end).unwrap_or_default();
+    }
+
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let maze = Grid::parse(input)?;
+        let find = |c: char| {
+            maze.find(&c)
+                .ok_or_else(|| ParseError::new(format!("expected the racetrack to contain `{c}`")))
+        };
+
+        Ok(Race {
+            start: find('S')?,
+            end: find('E')?,
+            maze,
+            path: Vec::new(),
+        })
+    }
+}
+
+fn get_count_of_ways_to_cheat(race: &Race, picosec: usize) -> usize {
+    let mut total = 0;
+    for i in 0..race.path.len().saturating_sub(3) {
+        for j in i + 3..race.path.len() {
+            let manhattan_distance = race.path[i].manhattan_distance(race.path[j]);
+                
+            if manhattan_distance <= picosec && (j - i) > manhattan_distance {
+                total += ((j - i) - manhattan_distance >= 100) as usize;
+            }
+        }
+    }
+    total
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Race;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut race = Race::from_input(input)?;
+        race.find_path();
+        Ok(race)
+    }
+
+    fn part1(race: &Self::Input) -> usize {
+        get_count_of_ways_to_cheat(race, 2)
+    }
+
+    fn part2(race: &Self::Input) -> usize {
+        get_count_of_ways_to_cheat(race, 20)
+    }
+}
@@ -0,0 +1,258 @@
+use common::search::bfs;
+use common::error::{parse_lines, parse_number};
+use common::{Direction4, Grid, ParseError, Point, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Key {
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    A,
+    Up,
+    Right,
+    Down,
+    Left,
+    Empty,
+}
+
+use Key::*;
+
+impl From<Direction4> for Key {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Up,
+            Direction4::Right => Right,
+            Direction4::Down => Down,
+            Direction4::Left => Left,
+        }
+    }
+}
+
+struct Keypad {
+    layout: Grid<Key>,
+}
+
+impl Keypad {
+    fn numpad() -> Self {
+        let layout = Grid::from_rows(vec![
+            vec![Num7, Num8, Num9],
+            vec![Num4, Num5, Num6],
+            vec![Num1, Num2, Num3],
+            vec![Empty, Num0, A],
+        ]);
+        Self { layout }
+    }
+
+    fn control() -> Self {
+        let layout = Grid::from_rows(vec![
+            vec![Empty, Up, A],
+            vec![Left, Down, Right],
+        ]);
+        Self { layout }
+    }
+
+    fn find_all_shortest_paths(&self) -> HashMap<Key, HashMap<Key, Vec<Vec<Key>>>> {
+        self.layout
+            .iter()
+            .filter(|(_, &key)| key != Empty)
+            .map(|(position, key)| (*key, self.find_shortest_paths(position)))
+            .collect()
+    }
+
+    /// The shortest presses from `start` to every other key, keeping only those that change
+    /// direction the fewest times since repeated presses are cheapest for the robot above.
+    fn find_shortest_paths(&self, start: Point<usize>) -> HashMap<Key, Vec<Vec<Key>>> {
+        let paths = bfs([start], |&position| {
+            self.layout
+                .neighbors4(position)
+                .filter(|&next| self.layout[next] != Empty)
+                .collect::<Vec<_>>()
+        });
+
+        paths
+            .costs()
+            .keys()
+            .map(|target| {
+                let presses: Vec<Vec<Key>> = paths
+                    .all_paths_to(target)
+                    .iter()
+                    .map(|path| self.to_presses(path))
+                    .collect();
+                let fewest_turns = presses.iter().map(|path| count_turns(path)).min().unwrap_or(0);
+                let presses = presses
+                    .into_iter()
+                    .filter(|path| count_turns(path) == fewest_turns)
+                    .map(|mut path| {
+                        path.push(A);
+                        path
+                    })
+                    .collect();
+
+                (self.layout[*target], presses)
+            })
+            .collect()
+    }
+
+    fn to_presses(&self, path: &[Point<usize>]) -> Vec<Key> {
+        path.windows(2)
+            .map(|step| {
+                let direction = Direction4::ALL
+                    .into_iter()
+                    .find(|&direction| self.layout.step(step[0], direction) == Some(step[1]))
+                    .expect("Consecutive keys on a path should be adjacent");
+                Key::from(direction)
+            })
+            .collect()
+    }
+}
+
+fn count_turns(presses: &[Key]) -> usize {
+    presses.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+struct PathFinder {
+    numpad_paths: HashMap<Key, HashMap<Key, Vec<Vec<Key>>>>,
+    control_paths: HashMap<Key, HashMap<Key, Vec<Vec<Key>>>>,
+}
+
+impl PathFinder {
+    fn new() -> Self {
+        let numpad_paths = Keypad::numpad().find_all_shortest_paths();
+        let control_paths = Keypad::control().find_all_shortest_paths();
+        Self {
+            numpad_paths,
+            control_paths,
+        }
+    }
+
+    fn find_complexity(&self, code: Vec<Key>, value: usize, max_depth: usize) -> usize {
+        let mut previous_key = A;
+        let mut total_len = 0;
+
+        for key in code {
+            total_len += self.find_recursive(
+                (previous_key, key),
+                max_depth,
+                0,
+                &mut HashMap::new(),
+                &mut HashMap::new(),
+            );
+            previous_key = key;
+        }
+
+        value * total_len
+    }
+
+    fn find_recursive(
+        &self,
+        path: (Key, Key),
+        max: usize,
+        current: usize,
+        memo: &mut HashMap<(Key, Key), HashMap<usize, usize>>,
+        last_at_level: &mut HashMap<usize, Key>,
+    ) -> usize {
+        if current == max {
+            return 1;
+        }
+
+        if let Some(cached) = memo.get(&path).and_then(|m| m.get(&current)) {
+            return *cached;
+        }
+
+        let paths = if current == 0 {
+            &self.numpad_paths
+        } else {
+            &self.control_paths
+        }
+        .get(&path.0)
+        .unwrap()
+        .get(&path.1)
+        .unwrap();
+
+        let last = *last_at_level.entry(current).or_insert(A);
+        let mut next_last = last;
+        let mut min_total = usize::MAX;
+
+        for possible_path in paths {
+            let mut sub_total = 0;
+            let mut previous = last;
+
+            for &part in possible_path {
+                sub_total +=
+                    self.find_recursive((previous, part), max, current + 1, memo, last_at_level);
+                previous = part;
+            }
+
+            if sub_total < min_total {
+                min_total = sub_total;
+                next_last = *possible_path.last().unwrap();
+            }
+        }
+
+        last_at_level.insert(current, next_last);
+        memo.entry(path).or_default().insert(current, min_total);
+        min_total
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Vec<Key>, usize)>, ParseError> {
+    parse_lines(input, |line| {
+        let code = (1..)
+            .zip(line.chars())
+            .map(|(column, c)| match c {
+                '0' => Ok(Num0),
+                '1' => Ok(Num1),
+                '2' => Ok(Num2),
+                '3' => Ok(Num3),
+                '4' => Ok(Num4),
+                '5' => Ok(Num5),
+                '6' => Ok(Num6),
+                '7' => Ok(Num7),
+                '8' => Ok(Num8),
+                '9' => Ok(Num9),
+                'A' => Ok(A),
+                _ => Err(ParseError::new(format!("expected a numeric keypad key, found `{c}`"))
+                    .with_column(column)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((code, parse_number(line.trim_end_matches('A'))?))
+    })
+}
+
+fn sum_complexities(codes: &[(Vec<Key>, usize)], max_depth: usize) -> usize {
+    let path_finder = PathFinder::new();
+
+    codes
+        .iter()
+        .map(|(code, value)| path_finder.find_complexity(code.clone(), *value, max_depth))
+        .sum()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<(Vec<Key>, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(codes: &Self::Input) -> usize {
+        sum_complexities(codes, 3)
+    }
+
+    fn part2(codes: &Self::Input) -> usize {
+        sum_complexities(codes, 26)
+    }
+}
@@ -0,0 +1,119 @@
+use common::error::{parse_lines, parse_number};
+use common::{ParseError, Solution};
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const PRUNE: isize = 16777216;
+
+fn next_number(previous: isize) -> isize {
+    let mut step_1 = previous * 64;
+    step_1 ^= previous;
+    step_1 %= PRUNE;
+
+    let mut step_2 = step_1 / 32;
+    step_2 ^= step_1;
+    step_2 %= PRUNE;
+
+    let mut final_step = step_2 * 2_048;
+    final_step ^= step_2;
+
+    final_step % PRUNE
+}
+
+pub struct SecretProcessor {
+    secrets: Vec<isize>,
+}
+
+impl SecretProcessor {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let secrets = parse_lines(input, parse_number)?;
+        if secrets.is_empty() {
+            return Err(ParseError::new("expected initial secrets, found empty input"));
+        }
+
+        Ok(Self { secrets })
+    }
+
+    fn process_part1(&self) -> isize {
+        let mut total = 0;
+        for &secret in &self.secrets {
+            let mut temp = secret;
+            for _ in 0..2_000 {
+                temp = next_number(temp);
+            }
+            total += temp;
+        }
+        total
+    }
+
+    fn process_part2(&self) -> isize {
+        let all_secrets: Vec<Vec<isize>> = self.secrets
+            .iter()
+            .map(|&code| {
+                let mut secrets = vec![code];
+                for _ in 0..2_000 {
+                    secrets.push(next_number(*secrets.last().unwrap()));
+                }
+                secrets.into_iter().map(|elem| elem % 10).collect()
+            })
+            .collect();
+
+        let mut changes_values: HashMap<(isize, isize, isize, isize), isize> = HashMap::new();
+
+        for secrets in all_secrets {
+            let mut changes: VecDeque<isize> = VecDeque::new();
+            let mut already_changed: HashSet<(isize, isize, isize, isize)> = HashSet::new();
+            for (i, code) in secrets.iter().enumerate().skip(1) {
+                changes.push_back(*code - secrets[i - 1]);
+
+                if changes.len() == 4 {
+                    let variation = (changes[0], changes[1], changes[2], changes[3]);
+                    if already_changed.insert(variation) {
+                        let total = changes_values.entry(variation).or_default();
+                        *total += code;
+                    }
+                    changes.pop_front();
+                }
+            }
+        }
+
+        let (_, total) = changes_values
+            .iter()
+            .max_by(|(_, &total_a), (_, total_b)| total_a.cmp(total_b))
+            .expect("SecretProcessor::new rejects input without secrets");
+
+        *total
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = SecretProcessor;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        SecretProcessor::new(input)
+    }
+
+    fn part1(processor: &Self::Input) -> isize {
+        processor.process_part1()
+    }
+
+    fn part2(processor: &Self::Input) -> isize {
+        processor.process_part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day22;
+    use common::Solution;
+
+    #[test]
+    fn rejects_empty_input() {
+        let error = Day22::parse("").err().unwrap();
+        assert_eq!(error.to_string(), "expected initial secrets, found empty input");
+    }
+}
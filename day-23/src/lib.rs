@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use common::error::{parse_lines, split_once};
+use common::{ParseError, Solution};
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn parse_graph(input: &str) -> Result<Graph, ParseError> {
+    let mut graph: Graph = HashMap::new();
+    parse_lines(input, |l| split_once(l, "-"))?
+        .into_iter()
+        .for_each(|(a, b)| {
+            graph.entry(a.to_string()).or_default().push(b.to_string());
+            graph.entry(b.to_string()).or_default().push(a.to_string());
+        });
+    if graph.is_empty() {
+        return Err(ParseError::new("expected connections, found empty input"));
+    }
+
+    Ok(graph)
+}
+
+fn find_password(graph: &Graph) -> String {
+    let highest_degree = graph
+        .values()
+        .map(|v| v.len())
+        .max()
+        .expect("parse_graph rejects input without connections");
+    let mut max_clique = Vec::new();
+    while max_clique.len() < highest_degree {
+        let mut ks = graph.keys().collect_vec();
+        ks.shuffle(&mut rand::rng());
+
+        let mut clique: Vec<&str> = Vec::new();
+        for k in ks {
+            if clique.iter().all(|c| graph[*c].contains(k)) {
+                let _ = &clique.push(k);
+            }
+        }
+
+        if clique.len() > max_clique.len() {
+            max_clique = clique;
+        }
+    }
+
+    max_clique.iter().sorted().join(",")
+}
+
+fn count_tri_cliques(graph: &Graph) -> i32 {
+    let ks = graph.keys().sorted().collect_vec();
+    let mut tri_cliques = 0;
+    for (i, a) in ks.iter().enumerate() {
+        for j in (i + 1)..ks.len() {
+            for k in (j + 1)..ks.len() {
+                let b = ks[j];
+                let c = ks[k];
+                if (a.starts_with('t') || b.starts_with('t') || c.starts_with('t'))
+                    && graph[b].contains(a)
+                    && graph[c].contains(a)
+                    && graph[c].contains(b)
+                {
+                    tri_cliques += 1;
+                }
+            }
+        }
+    }
+
+    tri_cliques
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Graph;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part1(graph: &Self::Input) -> i32 {
+        count_tri_cliques(graph)
+    }
+
+    fn part2(graph: &Self::Input) -> String {
+        find_password(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day23;
+    use common::Solution;
+
+    #[test]
+    fn rejects_empty_input() {
+        let error = Day23::parse("").err().unwrap();
+        assert_eq!(error.to_string(), "expected connections, found empty input");
+    }
+}
@@ -0,0 +1,444 @@
+use crate::{Device, Gate, Op};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// Why a device cannot be checked as an adder at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderError {
+    NoInputs,
+    MissingInput(String),
+}
+
+impl fmt::Display for AdderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdderError::NoInputs => write!(f, "there are no `x` or `y` input wires"),
+            AdderError::MissingInput(wire) => write!(f, "input wire `{wire}` is missing"),
+        }
+    }
+}
+
+impl Error for AdderError {}
+
+/// One structural fault, tied to the bit position it breaks and the wire that looks misplaced.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diagnostic {
+    pub bit: usize,
+    pub wire: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// What a gate does in a ripple-carry adder, worked out from its operator and inputs rather than
+/// from the name of the wire it drives, which is what a swap changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// `x XOR y` for a bit.
+    HalfSum(usize),
+    /// `x AND y` for a bit; for bit 0 this is the carry into bit 1.
+    Generate(usize),
+    /// The half sum XORed with the carry into a bit.
+    Sum(usize),
+    /// The half sum ANDed with the carry into a bit, one of the two terms of the next carry.
+    CarryTerm(usize),
+    /// The carry out of a bit.
+    Carry(usize),
+    /// A gate that has no place in an adder; it has already been reported.
+    Stray,
+}
+
+impl Role {
+    fn describe(self) -> String {
+        match self {
+            Role::HalfSum(bit) => format!("{} XOR {}", wire('x', bit), wire('y', bit)),
+            Role::Generate(bit) => format!("{} AND {}", wire('x', bit), wire('y', bit)),
+            Role::Sum(_) => "an XOR".to_string(),
+            Role::CarryTerm(_) => "an AND".to_string(),
+            Role::Carry(_) => "an OR".to_string(),
+            Role::Stray => "a stray gate".to_string(),
+        }
+    }
+}
+
+fn wire(prefix: char, bit: usize) -> String {
+    format!("{prefix}{bit:02}")
+}
+
+/// Splits an `x`, `y` or `z` wire into its prefix and bit position.
+pub(crate) fn bit_of(name: &str) -> Option<(char, usize)> {
+    let prefix = name.chars().next().filter(|c| matches!(c, 'x' | 'y' | 'z'))?;
+    let digits = &name[1..];
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((prefix, digits.parse().ok()?))
+}
+
+pub(crate) fn input_bit(name: &str) -> Option<(char, usize)> {
+    bit_of(name).filter(|(prefix, _)| *prefix != 'z')
+}
+
+fn output_bit(name: &str) -> Option<usize> {
+    bit_of(name).and_then(|(prefix, bit)| (prefix == 'z').then_some(bit))
+}
+
+/// Checks a device against the ripple-carry adder it is meant to be, one full-adder stage at a
+/// time. The width comes from the `x`/`y` inputs, so the sum has one more output bit than that.
+pub struct Adder<'a> {
+    width: usize,
+    drivers: HashMap<&'a str, &'a Gate>,
+    readers: HashMap<&'a str, Vec<&'a Gate>>,
+    roles: HashMap<&'a str, Role>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Adder<'a> {
+    pub fn new(device: &'a Device) -> Result<Self, AdderError> {
+        let width = device
+            .initial
+            .iter()
+            .filter_map(|(id, _)| input_bit(id))
+            .map(|(_, bit)| bit + 1)
+            .max()
+            .ok_or(AdderError::NoInputs)?;
+
+        for bit in 0..width {
+            for name in [wire('x', bit), wire('y', bit)] {
+                if !device.initial.iter().any(|(id, _)| *id == name) {
+                    return Err(AdderError::MissingInput(name));
+                }
+            }
+        }
+
+        let mut drivers = HashMap::new();
+        let mut readers: HashMap<&str, Vec<&Gate>> = HashMap::new();
+        for gate in &device.gates {
+            drivers.insert(gate.out.as_str(), gate);
+            for input in gate.inputs() {
+                readers.entry(input).or_default().push(gate);
+            }
+        }
+
+        let mut adder = Self {
+            width,
+            drivers,
+            readers,
+            roles: HashMap::new(),
+            diagnostics: Vec::new(),
+        };
+        let mut cones = HashMap::new();
+        for gate in &device.gates {
+            let role = adder.classify(gate, &mut cones);
+            adder.roles.insert(&gate.out, role);
+        }
+
+        Ok(adder)
+    }
+
+    /// The number of bits in each of the two numbers being added.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn report(&mut self, bit: usize, wire: &str, message: String) {
+        self.diagnostics.push(Diagnostic {
+            bit,
+            wire: wire.to_string(),
+            message,
+        });
+    }
+
+    /// The highest input bit `name` depends on, or `None` if it depends on no input at all.
+    fn cone(&self, name: &'a str, cones: &mut HashMap<&'a str, Option<usize>>) -> Option<usize> {
+        if let Some((_, bit)) = input_bit(name) {
+            return Some(bit);
+        }
+        if let Some(&bit) = cones.get(name) {
+            return bit;
+        }
+
+        // A miswired loop reads as depending on nothing rather than recursing forever.
+        cones.insert(name, None);
+        let gate = *self.drivers.get(name)?;
+        let bit = gate.inputs().map(|input| self.cone(input, cones)).max().flatten();
+        cones.insert(name, bit);
+        bit
+    }
+
+    fn classify(&mut self, gate: &'a Gate, cones: &mut HashMap<&'a str, Option<usize>>) -> Role {
+        let out = gate.out.as_str();
+        let (lhs, rhs) = match (gate.op, gate.rhs.as_deref()) {
+            (Op::And | Op::Or | Op::Xor, Some(rhs)) => (gate.lhs.as_str(), rhs),
+            (op, _) => {
+                let bit = self.cone(out, cones).unwrap_or_default();
+                let message = format!("`{out}` is a {op} gate, which has no place in an adder");
+                self.report(bit, out, message);
+                return Role::Stray;
+            }
+        };
+
+        match (input_bit(lhs), input_bit(rhs)) {
+            (Some((l, bit)), Some((r, other))) if l != r && bit == other => match gate.op {
+                Op::Xor => Role::HalfSum(bit),
+                Op::And => Role::Generate(bit),
+                op => {
+                    let message =
+                        format!("`{out}` is {lhs} {op} {rhs}, which has no place in an adder");
+                    self.report(bit, out, message);
+                    Role::Stray
+                }
+            },
+            (Some((_, bit)), Some((_, other))) => {
+                let message = format!(
+                    "`{out}` combines {lhs} and {rhs}, which are not the same bit of x and y"
+                );
+                self.report(bit.min(other), out, message);
+                Role::Stray
+            }
+            (Some((_, bit)), None) | (None, Some((_, bit))) => {
+                let (input, internal) = if input_bit(lhs).is_some() {
+                    (lhs, rhs)
+                } else {
+                    (rhs, lhs)
+                };
+                let message =
+                    format!("`{out}` combines input {input} with internal wire {internal}");
+                self.report(bit, out, message);
+                Role::Stray
+            }
+            (None, None) => match self.cone(out, cones) {
+                None => {
+                    self.report(0, out, format!("`{out}` does not depend on any input"));
+                    Role::Stray
+                }
+                Some(bit) => match gate.op {
+                    Op::Xor => Role::Sum(bit),
+                    Op::And => Role::CarryTerm(bit),
+                    Op::Or => Role::Carry(bit),
+                    op => unreachable!("{op} gates are reported before this"),
+                },
+            },
+        }
+    }
+
+    fn role(&self, name: &str) -> Option<Role> {
+        self.roles.get(name).copied()
+    }
+
+    /// What reads `name`, as in "an XOR and an AND" or "output z05".
+    fn fan_out(&self, name: &str) -> String {
+        let mut parts: Vec<String> = self
+            .readers
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|gate| {
+                let role = self.role(&gate.out).unwrap_or(Role::Stray);
+                match role {
+                    Role::Stray => "a stray gate".to_string(),
+                    role => role.describe(),
+                }
+            })
+            .collect();
+        if output_bit(name).is_some() {
+            parts.push(format!("output {name}"));
+        }
+
+        if parts.is_empty() {
+            "nothing".to_string()
+        } else {
+            parts.join(" and ")
+        }
+    }
+
+    fn feeds(&self, name: &str, expected: &[Op]) -> bool {
+        let mut ops: Vec<Op> = self
+            .readers
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|gate| gate.op)
+            .collect();
+        ops.sort_unstable();
+
+        output_bit(name).is_none() && ops == expected
+    }
+
+    /// Checks that a wire is read by exactly the gates its role calls for.
+    fn check_fan_out(&mut self, bit: usize, name: &str, what: String, expected: &[Op]) {
+        if !self.feeds(name, expected) {
+            let wanted = match expected {
+                [Op::Or] => "an OR",
+                _ => "an XOR and an AND",
+            };
+            let message = format!(
+                "{what} (`{name}`) should feed {wanted}, but feeds {}",
+                self.fan_out(name)
+            );
+            self.report(bit, name, message);
+        }
+    }
+
+    fn check_gate(&mut self, gate: &Gate) {
+        let out = gate.out.as_str();
+        let last = self.width - 1;
+
+        match self.role(out).unwrap_or(Role::Stray) {
+            Role::HalfSum(0) => {
+                if out != wire('z', 0) {
+                    let message = format!("x00 XOR y00 drives `{out}` rather than z00");
+                    self.report(0, out, message);
+                }
+            }
+            role @ Role::HalfSum(bit) => {
+                self.check_fan_out(bit, out, role.describe(), &[Op::And, Op::Xor]);
+            }
+            Role::Generate(0) if self.width == 1 => {
+                if out != wire('z', 1) {
+                    let message = format!("carry out of bit 0 drives `{out}` rather than z01");
+                    self.report(0, out, message);
+                }
+            }
+            Role::Generate(0) => {
+                self.check_fan_out(1, out, "carry into bit 1".to_string(), &[Op::And, Op::Xor]);
+            }
+            role @ Role::Generate(bit) => {
+                self.check_fan_out(bit, out, role.describe(), &[Op::Or]);
+            }
+            Role::Sum(bit) => {
+                if output_bit(out) != Some(bit) {
+                    let message = format!(
+                        "sum for bit {bit} drives `{out}` rather than {}",
+                        wire('z', bit)
+                    );
+                    self.report(bit, out, message);
+                }
+            }
+            Role::CarryTerm(bit) => {
+                let what = format!("carry term for bit {bit}");
+                self.check_fan_out(bit, out, what, &[Op::Or]);
+            }
+            Role::Carry(bit) if bit == last => {
+                let z = wire('z', self.width);
+                if out != z {
+                    let message =
+                        format!("carry out of the last bit drives `{out}` rather than {z}");
+                    self.report(bit, out, message);
+                }
+            }
+            Role::Carry(bit) => {
+                let what = format!("carry into bit {}", bit + 1);
+                self.check_fan_out(bit + 1, out, what, &[Op::And, Op::Xor]);
+            }
+            Role::Stray => {}
+        }
+    }
+
+    fn is_carry_into(&self, name: &str, bit: usize) -> bool {
+        match self.role(name) {
+            Some(Role::Generate(0)) => bit == 1,
+            Some(Role::Carry(_)) => bit > 1,
+            _ => false,
+        }
+    }
+
+    /// Checks that output `z` is driven by the right kind of gate, and for the sum outputs that
+    /// the gate reads the half sum of its own bit and a carry.
+    fn check_output(&mut self, bit: usize) {
+        let z = wire('z', bit);
+        let Some(gate) = self.drivers.get(z.as_str()).copied() else {
+            self.report(bit, &z, format!("output {z} is never set"));
+            return;
+        };
+
+        let actual = self.role(&z).unwrap_or(Role::Stray);
+        let expected = match bit {
+            0 => Role::HalfSum(0),
+            1 if self.width == 1 => Role::Generate(0),
+            bit if bit == self.width => Role::Carry(bit - 1),
+            bit => Role::Sum(bit),
+        };
+        let same_kind = std::mem::discriminant(&actual) == std::mem::discriminant(&expected);
+        if !same_kind {
+            let message = format!(
+                "output {z} is produced by {} rather than {}",
+                actual.describe(),
+                expected.describe()
+            );
+            self.report(bit, &z, message);
+            return;
+        }
+        if !matches!(actual, Role::Sum(_)) {
+            return;
+        }
+
+        let half = Role::HalfSum(bit);
+        let inputs = [gate.lhs.as_str(), gate.rhs.as_deref().unwrap_or_default()];
+        if let Some(index) = inputs.iter().position(|&name| self.role(name) == Some(half)) {
+            let carry = inputs[1 - index];
+            if !self.is_carry_into(carry, bit) {
+                let actual = self.role(carry).unwrap_or(Role::Stray).describe();
+                let expected = if bit == 1 {
+                    Role::Generate(0).describe()
+                } else {
+                    "an OR".to_string()
+                };
+                let message =
+                    format!("carry into bit {bit} is produced by {actual} rather than {expected}");
+                self.report(bit, carry, message);
+            }
+        } else {
+            for name in inputs {
+                if !self.is_carry_into(name, bit) {
+                    let message = format!(
+                        "sum for bit {bit} reads `{name}`, produced by {}, rather than {}",
+                        self.role(name).unwrap_or(Role::Stray).describe(),
+                        half.describe()
+                    );
+                    self.report(bit, name, message);
+                }
+            }
+        }
+    }
+
+    /// Every structural fault in the adder, ordered by the bit position it breaks.
+    pub fn verify(mut self) -> Vec<Diagnostic> {
+        let gates: Vec<&Gate> = self.drivers.values().copied().collect();
+        for gate in gates {
+            self.check_gate(gate);
+        }
+        for bit in 0..=self.width {
+            self.check_output(bit);
+        }
+
+        let beyond: Vec<&str> = self
+            .drivers
+            .keys()
+            .copied()
+            .filter(|name| output_bit(name).is_some_and(|bit| bit > self.width))
+            .collect();
+        for name in beyond {
+            let message = format!("output {name} is beyond the {}-bit sum", self.width + 1);
+            self.report(self.width, name, message);
+        }
+
+        self.diagnostics.sort_unstable();
+        self.diagnostics.dedup();
+        self.diagnostics
+    }
+}
+
+/// The wires that the diagnostics point at, sorted and joined with commas.
+pub fn suspicious_wires(diagnostics: &[Diagnostic]) -> String {
+    let mut wires: Vec<&str> = diagnostics.iter().map(|d| d.wire.as_str()).collect();
+    wires.sort_unstable();
+    wires.dedup();
+    wires.join(",")
+}
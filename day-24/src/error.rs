@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why a circuit cannot be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// A wire that a gate reads but that no gate drives and no initial value sets.
+    Undriven { wire: String, gate: String },
+    /// Wires that depend on themselves, in the order the loop runs through them.
+    Loop(Vec<String>),
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Undriven { wire, gate } => {
+                write!(f, "wire `{wire}`, read by `{gate}`, is never set")
+            }
+            CircuitError::Loop(wires) => write!(
+                f,
+                "combinational loop through {} -> {}",
+                wires.join(" -> "),
+                wires[0]
+            ),
+        }
+    }
+}
+
+impl Error for CircuitError {}
@@ -0,0 +1,166 @@
+use crate::adder::{bit_of, Adder};
+use crate::{Device, Gate, Op};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+fn color(op: Op) -> &'static str {
+    match op {
+        Op::And => "#9ecae1",
+        Op::Or => "#a1d99b",
+        Op::Xor => "#fdae6b",
+        Op::Nand => "#6baed6",
+        Op::Nor => "#74c476",
+        Op::Xnor => "#fd8d3c",
+        Op::Not => "#bcbddc",
+    }
+}
+
+/// The pieces both formats are drawn from. Every gate is a node named after the wire it drives,
+/// so an edge runs from each input wire to the gate that reads it.
+struct Network<'a> {
+    gates: &'a [Gate],
+    /// The `x`, `y` and `z` wires of each bit position, drawn together.
+    bits: BTreeMap<usize, Vec<&'a str>>,
+    /// The wires the adder verifier points at.
+    suspects: HashSet<String>,
+}
+
+impl<'a> Network<'a> {
+    fn new(device: &'a Device) -> Self {
+        let wires = device
+            .initial
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .chain(device.gates.iter().map(|gate| gate.out.as_str()));
+        let mut bits: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+        for wire in wires {
+            if let Some((_, bit)) = bit_of(wire) {
+                bits.entry(bit).or_default().push(wire);
+            }
+        }
+        for wires in bits.values_mut() {
+            wires.sort_unstable();
+        }
+
+        let suspects = Adder::new(device)
+            .map(|adder| adder.verify().into_iter().map(|d| d.wire).collect())
+            .unwrap_or_default();
+
+        Self {
+            gates: &device.gates,
+            bits,
+            suspects,
+        }
+    }
+
+    fn gate(&self, wire: &str) -> Option<&'a Gate> {
+        self.gates.iter().find(|gate| gate.out == wire)
+    }
+
+    fn is_grouped(&self, wire: &str) -> bool {
+        bit_of(wire).is_some_and(|(_, bit)| self.bits.contains_key(&bit))
+    }
+}
+
+fn dot_node(network: &Network, wire: &str) -> String {
+    let Some(gate) = network.gate(wire) else {
+        return format!("\"{wire}\" [shape=box];");
+    };
+
+    let mut node = format!(
+        "\"{wire}\" [label=\"{wire}\\n{}\", fillcolor=\"{}\"",
+        gate.op,
+        color(gate.op)
+    );
+    if network.suspects.contains(wire) {
+        node += ", color=red, penwidth=3";
+    }
+    node + "];"
+}
+
+/// Renders the gate network as a Graphviz digraph: gates are colored by operator, the `x`, `y`
+/// and `z` wires of each bit sit in one cluster, and gates driving a wire the adder verifier
+/// suspects are outlined in red.
+pub fn to_dot(device: &Device) -> String {
+    let network = Network::new(device);
+    let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+    dot += "    node [shape=ellipse, style=filled, fillcolor=white];\n";
+
+    for (bit, wires) in &network.bits {
+        let _ = writeln!(dot, "    subgraph cluster_bit_{bit:02} {{");
+        let _ = writeln!(dot, "        label=\"bit {bit:02}\";");
+        for wire in wires {
+            let _ = writeln!(dot, "        {}", dot_node(&network, wire));
+        }
+        dot += "    }\n";
+    }
+    for gate in network.gates {
+        if !network.is_grouped(&gate.out) {
+            let _ = writeln!(dot, "    {}", dot_node(&network, &gate.out));
+        }
+    }
+    for gate in network.gates {
+        for input in gate.inputs() {
+            let _ = writeln!(dot, "    \"{input}\" -> \"{}\";", gate.out);
+        }
+    }
+
+    dot + "}\n"
+}
+
+/// Mermaid ids are prefixed so that a wire named like a keyword such as `end` stays a node.
+fn mermaid_node(network: &Network, wire: &str) -> String {
+    match network.gate(wire) {
+        Some(gate) => format!("w_{wire}([\"{wire} {}\"])", gate.op),
+        None => format!("w_{wire}[\"{wire}\"]"),
+    }
+}
+
+/// Renders the gate network as a Mermaid flowchart with the same grouping, colors and highlights
+/// as [`to_dot`].
+pub fn to_mermaid(device: &Device) -> String {
+    let network = Network::new(device);
+    let mut mermaid = String::from("flowchart LR\n");
+
+    for (bit, wires) in &network.bits {
+        let _ = writeln!(mermaid, "    subgraph bit_{bit:02} [bit {bit:02}]");
+        for wire in wires {
+            let _ = writeln!(mermaid, "        {}", mermaid_node(&network, wire));
+        }
+        mermaid += "    end\n";
+    }
+    for gate in network.gates {
+        if !network.is_grouped(&gate.out) {
+            let _ = writeln!(mermaid, "    {}", mermaid_node(&network, &gate.out));
+        }
+    }
+    for gate in network.gates {
+        for input in gate.inputs() {
+            let _ = writeln!(mermaid, "    w_{input} --> w_{}", gate.out);
+        }
+    }
+
+    for op in Op::ALL {
+        let class = op.to_string().to_lowercase();
+        let _ = writeln!(mermaid, "    classDef {class} fill:{}", color(op));
+        let members: Vec<String> = network
+            .gates
+            .iter()
+            .filter(|gate| gate.op == op)
+            .map(|gate| format!("w_{}", gate.out))
+            .collect();
+        if !members.is_empty() {
+            let _ = writeln!(mermaid, "    class {} {class}", members.join(","));
+        }
+    }
+
+    let mut suspects: Vec<&String> = network.suspects.iter().collect();
+    if !suspects.is_empty() {
+        suspects.sort_unstable();
+        let members: Vec<String> = suspects.iter().map(|wire| format!("w_{wire}")).collect();
+        mermaid += "    classDef suspect stroke:#d00,stroke-width:3px\n";
+        let _ = writeln!(mermaid, "    class {} suspect", members.join(","));
+    }
+
+    mermaid
+}
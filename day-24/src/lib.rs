@@ -0,0 +1,520 @@
+pub mod adder;
+pub mod error;
+pub mod graph;
+pub mod repair;
+
+use adder::{input_bit, suspicious_wires, Adder, AdderError};
+use common::error::{split_once, split_sections};
+use common::{ParseError, Solution};
+use error::CircuitError;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Op {
+    And,
+    Or,
+    Xor,
+    Nand,
+    Nor,
+    Xnor,
+    Not,
+}
+
+impl Op {
+    const ALL: [Op; 7] = [Op::And, Op::Or, Op::Xor, Op::Nand, Op::Nor, Op::Xnor, Op::Not];
+
+    fn is_unary(self) -> bool {
+        self == Op::Not
+    }
+
+    /// The output for two input bits; `NOT` ignores `rhs`.
+    fn apply(self, lhs: u8, rhs: u8) -> u8 {
+        match self {
+            Op::And => lhs & rhs,
+            Op::Or => lhs | rhs,
+            Op::Xor => lhs ^ rhs,
+            Op::Nand => 1 ^ (lhs & rhs),
+            Op::Nor => 1 ^ (lhs | rhs),
+            Op::Xnor => 1 ^ lhs ^ rhs,
+            Op::Not => 1 ^ lhs,
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Op::ALL
+            .into_iter()
+            .find(|op| op.to_string() == s)
+            .ok_or_else(|| {
+                ParseError::new(format!(
+                    "expected `AND`, `OR`, `XOR`, `NAND`, `NOR`, `XNOR` or `NOT`, found `{s}`"
+                ))
+            })
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+            Op::Nand => "NAND",
+            Op::Nor => "NOR",
+            Op::Xnor => "XNOR",
+            Op::Not => "NOT",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Gate {
+    lhs: String,
+    op: Op,
+    /// `None` for `NOT`, the only gate with one input.
+    rhs: Option<String>,
+    out: String,
+}
+
+impl Gate {
+    fn inputs(&self) -> impl Iterator<Item = &str> {
+        iter::once(self.lhs.as_str()).chain(self.rhs.as_deref())
+    }
+}
+
+impl FromStr for Gate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (gate, out) = split_once(s, " -> ")?;
+        let (lhs, op, rhs) = match gate.split(' ').collect::<Vec<_>>()[..] {
+            [op, input] => (input, op.parse::<Op>()?, None),
+            [lhs, op, rhs] => (lhs, op.parse()?, Some(rhs.to_string())),
+            _ => {
+                return Err(ParseError::new(format!(
+                    "expected a gate like `a AND b` or `NOT a`, found `{gate}`"
+                )))
+            }
+        };
+        if op.is_unary() != rhs.is_none() {
+            let inputs = if op.is_unary() { "one input" } else { "two inputs" };
+            return Err(ParseError::new(format!("`{op}` takes {inputs}, found `{gate}`")));
+        }
+
+        Ok(Self {
+            lhs: lhs.to_string(),
+            op,
+            rhs,
+            out: out.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rhs {
+            Some(rhs) => write!(f, "{} {} {rhs} -> {}", self.lhs, self.op, self.out),
+            None => write!(f, "{} {} -> {}", self.op, self.lhs, self.out),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Device {
+    initial: Vec<(String, u8)>,
+    gates: Vec<Gate>,
+}
+
+/// The device as a netlist in the puzzle's input format.
+impl fmt::Display for Device {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (id, value) in &self.initial {
+            writeln!(f, "{id}: {value}")?;
+        }
+        writeln!(f)?;
+        for gate in &self.gates {
+            writeln!(f, "{gate}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Device {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let [init, conn] = split_sections(input)?;
+
+        let initial = init.parse_lines(|line| {
+            let (id, v) = split_once(line, ": ")?;
+            let value = match v.trim() {
+                "0" => 0,
+                "1" => 1,
+                v => return Err(ParseError::new(format!("expected `0` or `1`, found `{v}`"))),
+            };
+            Ok((id.to_string(), value))
+        })?;
+        let gates = conn.parse_lines(str::parse::<Gate>)?;
+
+        let mut drivers: HashMap<&str, usize> = HashMap::new();
+        for ((number, _), gate) in conn.lines().zip(&gates) {
+            if let Some(first) = drivers.insert(&gate.out, number) {
+                let message = format!("wire `{}` is already driven by line {first}", gate.out);
+                return Err(ParseError::new(message).with_line(number));
+            }
+        }
+
+        let mut wires: HashSet<&str> = initial.iter().map(|(id, _)| id.as_str()).collect();
+        wires.extend(drivers.keys());
+        for ((number, _), gate) in conn.lines().zip(&gates) {
+            for wire in gate.inputs() {
+                if !wires.contains(wire) {
+                    return Err(ParseError::new(format!("wire `{wire}` is never set"))
+                        .with_line(number));
+                }
+            }
+        }
+
+        Ok(Self { initial, gates })
+    }
+
+    /// Exchanges the wires that two gates drive.
+    fn swap_outputs(&mut self, a: &str, b: &str) {
+        for gate in &mut self.gates {
+            if gate.out == a {
+                gate.out = b.to_string();
+            } else if gate.out == b {
+                gate.out = a.to_string();
+            }
+        }
+    }
+}
+
+struct Circuit<'a> {
+    values: HashMap<&'a str, u8>,
+    gates: &'a [Gate],
+    outputs: Vec<&'a str>,
+}
+
+impl<'a> Circuit<'a> {
+    fn new(device: &'a Device) -> Self {
+        let values = device
+            .initial
+            .iter()
+            .map(|(id, v)| (id.as_str(), *v))
+            .collect();
+
+        let mut outputs: Vec<&str> = device
+            .gates
+            .iter()
+            .map(|gate| gate.out.as_str())
+            .filter(|out| out.starts_with('z'))
+            .collect();
+        outputs.sort_unstable();
+        outputs.reverse();
+
+        Self {
+            values,
+            gates: &device.gates,
+            outputs,
+        }
+    }
+
+    /// The circuit with its `x` and `y` wires set to the bits of two numbers instead of the
+    /// device's initial values.
+    fn with_inputs(device: &'a Device, x: u64, y: u64) -> Self {
+        let mut circuit = Self::new(device);
+        for (id, value) in &mut circuit.values {
+            *value = match input_bit(id) {
+                Some(('x', bit)) => (x >> bit & 1) as u8,
+                Some((_, bit)) => (y >> bit & 1) as u8,
+                None => *value,
+            };
+        }
+        circuit
+    }
+
+    /// Orders the gates so that each one comes after the gates driving its inputs, failing on a
+    /// wire nothing sets or on a loop. A wire with an initial value keeps it even if a gate
+    /// drives it too.
+    fn order(&self) -> Result<Vec<&'a Gate>, CircuitError> {
+        let drivers: HashMap<&str, usize> = self
+            .gates
+            .iter()
+            .enumerate()
+            .map(|(index, gate)| (gate.out.as_str(), index))
+            .collect();
+
+        let mut waiting = vec![0; self.gates.len()];
+        let mut readers: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (index, gate) in self.gates.iter().enumerate() {
+            for input in gate.inputs() {
+                if self.values.contains_key(input) {
+                    continue;
+                }
+                if !drivers.contains_key(input) {
+                    return Err(CircuitError::Undriven {
+                        wire: input.to_string(),
+                        gate: gate.to_string(),
+                    });
+                }
+                waiting[index] += 1;
+                readers.entry(input).or_default().push(index);
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.gates.len())
+            .filter(|&index| waiting[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.gates.len());
+        while let Some(index) = ready.pop_front() {
+            let gate = &self.gates[index];
+            order.push(gate);
+            for &reader in readers.get(gate.out.as_str()).into_iter().flatten() {
+                waiting[reader] -= 1;
+                if waiting[reader] == 0 {
+                    ready.push_back(reader);
+                }
+            }
+        }
+
+        if order.len() < self.gates.len() {
+            return Err(CircuitError::Loop(self.find_loop(&waiting, &drivers)));
+        }
+        Ok(order)
+    }
+
+    /// Walks back from a gate that never became ready. Each such gate reads a wire driven by
+    /// another one, so the walk must come back around to a wire it has already seen. The loop is
+    /// returned in the direction values flow, starting from its smallest wire.
+    fn find_loop(&self, waiting: &[usize], drivers: &HashMap<&str, usize>) -> Vec<String> {
+        let stuck = |wire: &str| {
+            drivers
+                .get(wire)
+                .copied()
+                .filter(|&index| waiting[index] > 0 && !self.values.contains_key(wire))
+        };
+
+        let mut index = waiting.iter().position(|&count| count > 0).unwrap_or_default();
+        let mut path: Vec<&str> = Vec::new();
+        loop {
+            let gate = &self.gates[index];
+            if let Some(start) = path.iter().position(|&wire| wire == gate.out) {
+                let mut wires: Vec<String> = path[start..].iter().map(|w| w.to_string()).collect();
+                wires.reverse();
+                let first = (0..wires.len()).min_by_key(|&index| &wires[index]);
+                wires.rotate_left(first.unwrap_or_default());
+                return wires;
+            }
+            path.push(&gate.out);
+            match gate.inputs().find_map(stuck) {
+                Some(next) => index = next,
+                None => return path.iter().map(|w| w.to_string()).collect(),
+            }
+        }
+    }
+
+    /// Evaluates every gate in dependency order and reads the `z` wires as a number, most
+    /// significant first.
+    fn evaluate(&mut self) -> Result<u64, CircuitError> {
+        for gate in self.order()? {
+            let lhs = self.values[gate.lhs.as_str()];
+            let rhs = gate.rhs.as_deref().map_or(0, |rhs| self.values[rhs]);
+            self.values
+                .entry(&gate.out)
+                .or_insert(gate.op.apply(lhs, rhs));
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .fold(0, |result, output| (result << 1) | self.values[output] as u64))
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Device;
+    type Answer1 = Result<u64, CircuitError>;
+    type Answer2 = Result<String, AdderError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Device::from_input(input)
+    }
+
+    fn part1(device: &Self::Input) -> Result<u64, CircuitError> {
+        Circuit::new(device).evaluate()
+    }
+
+    fn part2(device: &Self::Input) -> Result<String, AdderError> {
+        Adder::new(device).map(|adder| suspicious_wires(&adder.verify()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adder::{suspicious_wires, Adder};
+    use crate::error::CircuitError;
+    use crate::graph::{to_dot, to_mermaid};
+    use crate::repair::{confirm, repair, RepairError, RANDOM_VECTORS};
+    use crate::{Circuit, Day24, Device};
+    use common::Part::{Part1, Part2};
+    use common::Solution;
+
+    const INPUT: &str = include_str!("../input.txt");
+    const TEST_2: &str = include_str!("../test-2.txt");
+
+    #[test]
+    fn a_correct_adder_has_no_diagnostics() {
+        let device = Device::from_input(TEST_2).unwrap();
+        let adder = Adder::new(&device).unwrap();
+        assert_eq!(adder.width(), 3);
+        assert_eq!(adder.verify(), vec![]);
+        assert_eq!(Day24::solve(TEST_2, Part1).unwrap(), "8");
+    }
+
+    #[test]
+    fn diagnostics_name_the_broken_bit_and_the_swapped_wires() {
+        let swapped = TEST_2
+            .replace("-> z01", "-> tmp")
+            .replace("-> t01", "-> z01")
+            .replace("-> tmp", "-> t01");
+        let device = Device::from_input(&swapped).unwrap();
+        let diagnostics = Adder::new(&device).unwrap().verify();
+
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.bit == 1));
+        assert_eq!(suspicious_wires(&diagnostics), "t01,z01");
+        let messages: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+        assert!(messages.contains(&"sum for bit 1 drives `t01` rather than z01".to_string()));
+        let wrong_gate = "output z01 is produced by an AND rather than an XOR";
+        assert!(messages.contains(&wrong_gate.to_string()));
+    }
+
+    #[test]
+    fn explains_why_the_carry_into_a_bit_is_wrong() {
+        let device = Device::from_input(INPUT).unwrap();
+        let diagnostics = Adder::new(&device).unwrap().verify();
+
+        assert!(diagnostics.iter().any(|diagnostic| diagnostic.bit == 25
+            && diagnostic.message == "carry into bit 25 is produced by an XOR rather than an OR"));
+    }
+
+    #[test]
+    fn rejects_a_circuit_without_matching_inputs() {
+        let input = TEST_2.replace("x02: 1\n", "x02: 1\nx03: 1\n");
+        let error = Day24::solve(&input, Part2).unwrap_err();
+        assert_eq!(error.to_string(), "part 2: input wire `y03` is missing");
+    }
+
+    #[test]
+    fn repair_finds_the_swaps_and_confirms_the_input_adds() {
+        let device = Device::from_input(INPUT).unwrap();
+        let repair = repair(&device, 4).unwrap();
+
+        assert_eq!(repair.swaps.len(), 4);
+        assert_eq!(repair.wires(), "jgb,rkf,rrs,rvc,vcg,z09,z20,z24");
+        assert_eq!(repair.vectors, 45 * 8 - 4 + RANDOM_VECTORS);
+    }
+
+    #[test]
+    fn repair_undoes_a_swap_that_made_a_loop() {
+        let swapped = TEST_2.replace("-> z02", "-> tmp").replace("-> c02", "-> z02");
+        let swapped = swapped.replace("-> tmp", "-> c02");
+        let device = Device::from_input(&swapped).unwrap();
+        let repaired = repair(&device, 1).unwrap();
+
+        assert_eq!(repaired.swaps, vec![("c02".to_string(), "z02".to_string())]);
+        assert_eq!(repaired.device.to_string(), TEST_2);
+        let error = RepairError::NoRepair { bit: 0, max_swaps: 0 };
+        assert_eq!(repair(&device, 0).err(), Some(error));
+    }
+
+    #[test]
+    fn confirm_rejects_widths_a_u64_sum_cannot_hold() {
+        let device = Device::from_input(TEST_2).unwrap();
+
+        assert_eq!(confirm(&device, 3), Ok(3 * 8 - 4 + RANDOM_VECTORS));
+        assert_eq!(confirm(&device, 0), Err(RepairError::Width(0)));
+        assert_eq!(confirm(&device, 64), Err(RepairError::Width(64)));
+        assert_eq!(
+            RepairError::Width(64).to_string(),
+            "cannot check a 64-bit adder; it must have 1 to 63 bits"
+        );
+    }
+
+    #[test]
+    fn dot_groups_wires_by_bit_and_highlights_suspects() {
+        let swapped = TEST_2
+            .replace("-> z01", "-> tmp")
+            .replace("-> t01", "-> z01")
+            .replace("-> tmp", "-> t01");
+        let dot = to_dot(&Device::from_input(&swapped).unwrap());
+
+        assert!(dot.starts_with("digraph circuit {\n"));
+        assert!(dot.contains("    subgraph cluster_bit_01 {\n        label=\"bit 01\";\n"));
+        assert!(dot.contains("        \"x01\" [shape=box];\n"));
+        let suspect = "[label=\"z01\\nAND\", fillcolor=\"#9ecae1\", color=red, penwidth=3];";
+        assert!(dot.contains(&format!("\"z01\" {suspect}")));
+        assert!(dot.contains("    \"c02\" [label=\"c02\\nOR\", fillcolor=\"#a1d99b\"];\n"));
+        assert!(dot.contains("    \"s01\" -> \"t01\";\n"));
+    }
+
+    #[test]
+    fn mermaid_classes_gates_by_operator() {
+        let mermaid = to_mermaid(&Device::from_input(TEST_2).unwrap());
+
+        let bit = "    subgraph bit_03 [bit 03]\n        w_z03([\"z03 OR\"])\n    end\n";
+        assert!(mermaid.contains(bit));
+        assert!(mermaid.contains("    w_g01 --> w_c02\n"));
+        assert!(mermaid.contains("    class w_c02,w_z03 or\n"));
+        assert!(!mermaid.contains("suspect"));
+    }
+
+    #[test]
+    fn evaluates_inverting_and_unary_gates() {
+        let input = "a: 1\nb: 0\n\n\
+            a NAND b -> z00\na NOR b -> z01\na XNOR b -> z02\nNOT z01 -> z03\n";
+        let device = Device::from_input(input).unwrap();
+
+        assert_eq!(Day24::solve(input, Part1).unwrap(), "9");
+        assert_eq!(device.to_string(), input);
+        let error = Day24::parse("a: 1\n\na NOT a -> z00").err().unwrap();
+        assert_eq!(error.to_string(), "line 3: `NOT` takes one input, found `a NOT a`");
+        let error = Day24::parse("a: 1\n\nNAND a -> z00").err().unwrap();
+        assert_eq!(error.to_string(), "line 3: `NAND` takes two inputs, found `NAND a`");
+    }
+
+    #[test]
+    fn rejects_wires_with_two_drivers_and_values_other_than_bits() {
+        let input = "x00: 1\ny00: 0\n\nx00 AND y00 -> a\nx00 OR y00 -> a\na XOR x00 -> z00";
+        let error = Day24::parse(input).err().unwrap();
+        assert_eq!(error.to_string(), "line 5: wire `a` is already driven by line 4");
+
+        let error = Day24::parse("x00: 7\n\nNOT x00 -> z00").err().unwrap();
+        assert_eq!(error.to_string(), "line 1: expected `0` or `1`, found `7`");
+    }
+
+    #[test]
+    fn reports_loops_instead_of_overflowing_the_stack() {
+        let input = "x00: 1\n\nx00 AND c -> a\na OR x00 -> b\nb XOR x00 -> c\nc OR x00 -> z00\n";
+        let error = Day24::solve(input, Part1).unwrap_err();
+        let message = "part 1: combinational loop through a -> b -> c -> a";
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[test]
+    fn reports_undriven_wires() {
+        let mut device = Device::from_input(TEST_2).unwrap();
+        device.initial.retain(|(id, _)| id != "y02");
+        let error = Circuit::new(&device).evaluate().unwrap_err();
+
+        let gate = "x02 XOR y02 -> s02".to_string();
+        assert_eq!(error, CircuitError::Undriven { wire: "y02".to_string(), gate });
+    }
+}
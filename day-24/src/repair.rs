@@ -0,0 +1,225 @@
+use crate::adder::{Adder, AdderError};
+use crate::error::CircuitError;
+use crate::{Circuit, Device};
+use std::error::Error;
+use std::fmt;
+
+/// How many random additions a repair is checked against on top of the per-bit vectors.
+pub const RANDOM_VECTORS: usize = 1_000;
+/// The widest adder that can be checked: its sum needs one more bit, and sums are `u64`s.
+pub const MAX_WIDTH: usize = 63;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepairError {
+    NotAnAdder(AdderError),
+    /// The repaired circuit cannot be evaluated.
+    Circuit(CircuitError),
+    /// No combination of swaps within the limit fixes the lowest broken bit.
+    NoRepair { bit: usize, max_swaps: usize },
+    /// The adder is too wide, or has no bits, to check with `u64` additions.
+    Width(usize),
+    /// The repaired circuit still adds two numbers wrongly.
+    Mismatch { x: u64, y: u64, actual: u64 },
+}
+
+impl fmt::Display for RepairError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairError::NotAnAdder(error) => write!(f, "the circuit is not an adder: {error}"),
+            RepairError::Circuit(error) => write!(f, "cannot evaluate the circuit: {error}"),
+            RepairError::NoRepair { bit, max_swaps } => {
+                write!(f, "no {max_swaps} swaps or fewer repair bit {bit}")
+            }
+            RepairError::Width(width) => write!(
+                f,
+                "cannot check a {width}-bit adder; it must have 1 to {MAX_WIDTH} bits"
+            ),
+            RepairError::Mismatch { x, y, actual } => write!(
+                f,
+                "the repaired circuit computes {x} + {y} as {actual} rather than {}",
+                x + y
+            ),
+        }
+    }
+}
+
+impl Error for RepairError {}
+
+/// A set of output swaps under which the device adds, and the repaired device itself.
+pub struct Repair {
+    pub swaps: Vec<(String, String)>,
+    pub device: Device,
+    /// How many additions the repaired device was confirmed against.
+    pub vectors: usize,
+}
+
+impl Repair {
+    /// Every swapped wire, sorted and joined with commas.
+    pub fn wires(&self) -> String {
+        let mut wires: Vec<&str> = self
+            .swaps
+            .iter()
+            .flat_map(|(a, b)| [a.as_str(), b.as_str()])
+            .collect();
+        wires.sort_unstable();
+        wires.join(",")
+    }
+}
+
+/// A xorshift generator with a fixed seed, so that confirmation is repeatable.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Every combination of the two input bits at `bit` and a carry into it, which comes from setting
+/// both bits below.
+fn bit_vectors(bit: usize) -> impl Iterator<Item = (u64, u64)> {
+    (0..8u64).filter_map(move |combination| {
+        let carry = combination >> 2;
+        if bit == 0 && carry == 1 {
+            return None;
+        }
+        let below = carry << bit >> 1;
+        Some((
+            (combination & 1) << bit | below,
+            (combination >> 1 & 1) << bit | below,
+        ))
+    })
+}
+
+fn adds(device: &Device, x: u64, y: u64) -> Result<(), RepairError> {
+    let actual = Circuit::with_inputs(device, x, y)
+        .evaluate()
+        .map_err(RepairError::Circuit)?;
+    if actual == x + y {
+        Ok(())
+    } else {
+        Err(RepairError::Mismatch { x, y, actual })
+    }
+}
+
+fn adds_at(device: &Device, bit: usize) -> bool {
+    bit_vectors(bit).all(|(x, y)| adds(device, x, y).is_ok())
+}
+
+/// The lowest bit from `from` up whose vectors fail.
+fn first_failure(device: &Device, width: usize, from: usize) -> Option<usize> {
+    (from..width).find(|&bit| !adds_at(device, bit))
+}
+
+struct Search<'a> {
+    width: usize,
+    max_swaps: usize,
+    candidates: &'a [&'a str],
+    swaps: Vec<(String, String)>,
+}
+
+impl Search<'_> {
+    /// Tries every pair of candidates on the lowest broken bit, keeping a swap only if that bit
+    /// and every bit below it then add correctly, and backtracks when the bits above cannot be
+    /// fixed with the swaps left.
+    fn fix(&mut self, device: &mut Device, bit: usize) -> bool {
+        if self.swaps.len() == self.max_swaps {
+            return false;
+        }
+
+        for (index, &a) in self.candidates.iter().enumerate() {
+            for &b in &self.candidates[index + 1..] {
+                device.swap_outputs(a, b);
+                let fixed = adds_at(device, bit)
+                    && (0..bit).all(|below| adds_at(device, below));
+
+                if fixed {
+                    self.swaps.push((a.to_string(), b.to_string()));
+                    match first_failure(device, self.width, bit + 1) {
+                        None => return true,
+                        Some(next) if self.fix(device, next) => return true,
+                        Some(_) => {
+                            self.swaps.pop();
+                        }
+                    }
+                }
+                device.swap_outputs(a, b);
+            }
+        }
+
+        false
+    }
+}
+
+fn check_width(width: usize) -> Result<(), RepairError> {
+    if (1..=MAX_WIDTH).contains(&width) {
+        Ok(())
+    } else {
+        Err(RepairError::Width(width))
+    }
+}
+
+/// Confirms that `device` adds, first with every per-bit vector and then with random numbers.
+/// Returns how many additions were checked.
+pub fn confirm(device: &Device, width: usize) -> Result<usize, RepairError> {
+    check_width(width)?;
+    let mask = u64::MAX >> (64 - width);
+    let mut rng = Rng(0x2024_1224);
+    let random = (0..RANDOM_VECTORS).map(|_| (rng.next() & mask, rng.next() & mask));
+    let vectors: Vec<(u64, u64)> = (0..width).flat_map(bit_vectors).chain(random).collect();
+
+    for &(x, y) in &vectors {
+        adds(device, x, y)?;
+    }
+
+    Ok(vectors.len())
+}
+
+/// Searches for at most `max_swaps` swaps of gate outputs that make the device add `x + y`,
+/// working up from the lowest bit that adds wrongly. The wires the verifier suspects are tried
+/// first and every gate output after that. The result is confirmed before it is returned.
+pub fn repair(device: &Device, max_swaps: usize) -> Result<Repair, RepairError> {
+    let adder = Adder::new(device).map_err(RepairError::NotAnAdder)?;
+    let width = adder.width();
+    check_width(width)?;
+    let mut suspects: Vec<String> = adder.verify().into_iter().map(|d| d.wire).collect();
+    suspects.sort_unstable();
+    suspects.dedup();
+
+    let mut repaired = device.clone();
+    let mut swaps = Vec::new();
+
+    if let Some(bit) = first_failure(&repaired, width, 0) {
+        let suspects: Vec<&str> = suspects
+            .iter()
+            .map(String::as_str)
+            .filter(|wire| device.gates.iter().any(|gate| gate.out == *wire))
+            .collect();
+        let outputs: Vec<&str> = device.gates.iter().map(|gate| gate.out.as_str()).collect();
+
+        let found = [suspects, outputs].iter().any(|candidates| {
+            let mut search = Search {
+                width,
+                max_swaps,
+                candidates,
+                swaps: Vec::new(),
+            };
+            let found = search.fix(&mut repaired, bit);
+            swaps = search.swaps;
+            found
+        });
+        if !found {
+            return Err(RepairError::NoRepair { bit, max_swaps });
+        }
+    }
+
+    let vectors = confirm(&repaired, width)?;
+    Ok(Repair {
+        swaps,
+        device: repaired,
+        vectors,
+    })
+}
@@ -1,22 +1,9 @@
 #!/bin/bash
+# Scaffolds the day after the latest `day-NN` folder, or the day passed as the first argument.
 
-day=$(ls -d day-* 2>/dev/null | sed 's/day-0*//' | sort -n | tail -1)
-if [ -z "$day" ]; then
-    day=0
+day=${1:-$(ls -d day-* 2>/dev/null | sed 's/day-0*//' | sort -n | tail -1)}
+if [ -z "$1" ]; then
+    day=$((${day:-0} + 1))
 fi
-day=$((day + 1))
 
-formatted_day=$(printf "%02d" $day)
-new_folder="day-$formatted_day" 
-
-mkdir $new_folder
-cargo init $new_folder
-touch $new_folder/test.txt
-
-cp aoc.template.rs $new_folder/src/main.rs
-
-export "$(cat .env | xargs)"
-
-url="https://adventofcode.com/2024/day/${day}"
-title="$(curl -s --cookie "session=${SESSION_COOKIE}" "$url" | pup 'article h2 text{}' | sed 's/--- Day [0-9]*: \(.*\) ---/\1/')"
-echo "| ${day}   | [${title}](https://adventofcode.com/2024/day/${day}) | ★★ |" >> README.md
\ No newline at end of file
+cargo run -q --release -p aoc -- scaffold --day "$day"
@@ -1,20 +1,6 @@
 #!/bin/bash
+# Fetches the input for the latest `day-NN` folder, or the day passed as the first argument.
 
-day=$(ls -d day-* 2>/dev/null | sed 's/day-0*//' | sort -n | tail -1)
-if [ -z "$day" ]; then
-    day=0
-fi
+day=${1:-$(ls -d day-* 2>/dev/null | sed 's/day-0*//' | sort -n | tail -1)}
 
-echo "$day"
-
-url="https://adventofcode.com/2024/day/${day}/input"
-
-
-formatted_day=$(printf "%02d" $day)
-new_folder="day-$formatted_day"
-
-mkdir -p "${new_folder}"
-
-export $(cat .env | xargs)
-
-curl -b "session=${SESSION_COOKIE}" "$url" > "${new_folder}/input.txt"
\ No newline at end of file
+cargo run -q --release -p aoc -- fetch --day "$day"